@@ -1,16 +1,21 @@
+use crate::game::{RoadLayout, TableLayout, TowerCatalogue, WaveCampaign};
 use crate::prelude::*;
 use crate::utils::reflect::get_field_mut;
 use crate::utils::reflect::prop_name;
 use crate::utils::reflect::set_field;
 use crate::AppState;
+use bevy::asset::io::Reader;
 use bevy::asset::Asset;
 use bevy::asset::LoadState;
+use bevy::asset::{AssetLoader, LoadContext};
 use bevy::ecs::resource::Resource;
 use bevy::gltf::{Gltf, GltfMesh};
 use bevy::platform::collections::HashMap;
 pub use bevy_asset_loader::prelude::*;
 use rand::seq::IndexedRandom;
+use serde::de::DeserializeOwned;
 use std::env;
+use std::marker::PhantomData;
 use std::path::PathBuf;
 
 #[derive(AssetCollection, Resource, Default)]
@@ -21,6 +26,16 @@ pub struct PinballDefenseAssets {
 
     #[asset(path = "fonts/hemi_head.otf")]
     pub menu_font: Handle<Font>,
+
+    // Game data
+    #[asset(path = "data/world_1.waves.ron")]
+    pub waves: Handle<WaveCampaign>,
+    #[asset(path = "data/towers.ron")]
+    pub towers: Handle<TowerCatalogue>,
+    #[asset(path = "data/world_1.table.ron")]
+    pub table: Handle<TableLayout>,
+    #[asset(path = "data/world_1.roads.ron")]
+    pub roads: Handle<RoadLayout>,
 }
 
 #[derive(Resource, Reflect, Default)]
@@ -354,3 +369,80 @@ fn file_paths(dir: PathBuf) -> Vec<(String, PathBuf)> {
         })
         .collect()
 }
+
+// Checks of a loaded RON asset, which serde can not express
+pub trait ValidateAsset {
+    fn validate(&self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+// Loads every asset, which is described in a RON file, e.g. wave campaigns
+#[derive(TypePath)]
+pub struct RonAssetLoader<T> {
+    extensions: &'static [&'static str],
+    _asset: PhantomData<fn() -> T>,
+}
+
+impl<T> RonAssetLoader<T> {
+    pub fn new(extensions: &'static [&'static str]) -> Self {
+        Self {
+            extensions,
+            _asset: PhantomData,
+        }
+    }
+}
+
+impl<T: Asset + DeserializeOwned + ValidateAsset> AssetLoader for RonAssetLoader<T> {
+    type Asset = T;
+    type Settings = ();
+    type Error = RonAssetLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let asset = ron::de::from_bytes::<T>(&bytes)?;
+        asset.validate().map_err(RonAssetLoaderError::Invalid)?;
+        Ok(asset)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        self.extensions
+    }
+}
+
+#[derive(Debug)]
+pub enum RonAssetLoaderError {
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+    Invalid(String),
+}
+
+impl std::fmt::Display for RonAssetLoaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "😭 Can not read RON asset: {err}"),
+            Self::Ron(err) => write!(f, "😭 Can not parse RON asset: {err}"),
+            Self::Invalid(err) => write!(f, "😭 Invalid RON asset: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for RonAssetLoaderError {}
+
+impl From<std::io::Error> for RonAssetLoaderError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<ron::error::SpannedError> for RonAssetLoaderError {
+    fn from(err: ron::error::SpannedError) -> Self {
+        Self::Ron(err)
+    }
+}
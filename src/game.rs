@@ -4,8 +4,12 @@ use ball::BallPlugin;
 use self::analog_counter::AnalogCounterPlugin;
 use self::audio::AudioPlugin;
 use self::ball::PinBall;
+use self::ball_inventory::BallInventoryPlugin;
+use self::ball_save::BallSavePlugin;
 use self::ball_starter::BallStarterPlugin;
+use self::base::BasePlugin;
 use self::camera::PinballCameraPlugin;
+use self::drill::DrillPlugin;
 use self::flipper::FlipperPlugin;
 use self::game_over::GameOverScreen;
 use self::health::HealthPlugin;
@@ -21,19 +25,33 @@ pub use controls::KeyboardControls;
 use enemy::EnemyPlugin;
 use events::PinballEventsPlugin;
 use pinball_menu::PinballMenuPlugin;
-use player_life::PlayerLifePlugin;
+use power::PowerPlugin;
+use power_up::PowerUpPlugin;
 use progress::ProgressPlugin;
+pub use road::RoadLayout;
+use road::RoadPlugin;
+use shockwave::ShockwavePlugin;
+use skill_shot::SkillShotPlugin;
 use std::f32::consts::PI;
+pub use table::TableLayout;
+use table::TablePlugin;
+use tilt::TiltPlugin;
+pub use tower::TowerCatalogue;
 use tower::TowerPlugin;
+pub use wave::WaveCampaign;
 use wave::WavePlugin;
 
 mod analog_counter;
 mod audio;
 mod ball;
+mod ball_inventory;
+mod ball_save;
 mod ball_starter;
+mod base;
 mod camera;
 mod cfg;
 mod controls;
+mod drill;
 mod enemy;
 mod events;
 mod flipper;
@@ -42,9 +60,14 @@ mod health;
 mod level;
 mod light;
 mod pinball_menu;
-mod player_life;
+mod power;
+mod power_up;
 mod progress;
 mod road;
+mod shockwave;
+mod skill_shot;
+mod table;
+mod tilt;
 mod tower;
 mod ui;
 mod wave;
@@ -58,6 +81,7 @@ enum GameState {
     Ingame,
     Pause,
     GameOver,
+    Victory,
 }
 
 #[derive(States, PartialEq, Eq, Clone, Copy, Debug, Hash, Default)]
@@ -89,13 +113,23 @@ impl Plugin for GamePlugin {
                 LevelPlugin,
                 AnalogCounterPlugin,
                 AudioPlugin,
+                RoadPlugin,
             ))
             .add_plugins((
                 HealthPlugin,
-                PlayerLifePlugin,
+                BasePlugin,
+                PowerPlugin,
+                DrillPlugin,
+                PowerUpPlugin,
+                ShockwavePlugin,
+                TiltPlugin,
+                TablePlugin,
                 LightPlugin,
                 FlipperPlugin,
                 BallStarterPlugin,
+                BallSavePlugin,
+                BallInventoryPlugin,
+                SkillShotPlugin,
                 self::ui::UiPlugin,
             ))
             .add_systems(
@@ -112,11 +146,14 @@ impl Plugin for GamePlugin {
             )
             .add_systems(OnEnter(AppState::Game), init_game)
             .add_systems(OnEnter(GameState::GameOver), game_over::spawn)
+            .add_systems(OnEnter(GameState::Victory), game_over::spawn_victory)
             .add_systems(
                 Update,
-                (game_over::btn_system).run_if(in_state(GameState::GameOver)),
+                (game_over::btn_system)
+                    .run_if(in_state(GameState::GameOver).or(in_state(GameState::Victory))),
             )
-            .add_systems(OnExit(GameState::GameOver), reset);
+            .add_systems(OnExit(GameState::GameOver), reset)
+            .add_systems(OnExit(GameState::Victory), reset);
     }
 }
 
@@ -174,8 +174,9 @@ fn ball_rolling_sound_system(
     sound_sett: Res<SoundSettings>,
 ) {
     if let Ok(mut sound) = q_rolling_sound.single_mut() {
-        if let Some(vel) = q_ball.iter().next() {
-            let linvel = vel.length().abs() / 12.;
+        // The fastest ball is the loudest
+        if let Some(vel) = q_ball.iter().map(|vel| vel.length()).reduce(f32::max) {
+            let linvel = vel / 12.;
             sound.set_volume(Volume::Linear(linvel * sound_sett.fx_volume));
             let speed = 0.9 + linvel / 2.;
             sound.set_speed(speed);
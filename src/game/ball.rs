@@ -1,9 +1,11 @@
 use super::audio::SoundEvent;
+use super::ball_save::BallSave;
+use super::ball_starter::BallSpawn;
+use super::base::{nearest_building, QueryBuildings};
 use super::events::collision::GameLayer;
 use super::health::ChangeHealthEvent;
 use super::level::PointsEvent;
 use super::pinball_menu::PinballMenuEvent;
-use super::player_life::LifeBar;
 use super::world::WorldFrame;
 use super::EventState;
 use super::GameState;
@@ -20,7 +22,12 @@ impl Plugin for BallPlugin {
             .add_message::<CollisionWithBallEvent>()
             .add_systems(
                 Update,
-                (ball_reset_system, clamp_ball_speed_system).run_if(in_state(GameState::Ingame)),
+                (
+                    ball_reset_system,
+                    clamp_ball_speed_system,
+                    extra_ball_timeout_system,
+                )
+                    .run_if(in_state(GameState::Ingame)),
             )
             .add_systems(
                 Update,
@@ -42,7 +49,7 @@ pub fn spawn(
     meshes: &mut Assets<Mesh>,
     materials: &mut Assets<StandardMaterial>,
     pos: Vec3,
-) {
+) -> Entity {
     let radius = 0.005;
     cmds.spawn((
         Mesh3d(meshes.add(Mesh::from(Sphere {
@@ -71,9 +78,14 @@ pub fn spawn(
         Friction::from(0.02),
         PinBall,
         Name::new("Ball"),
-    ));
+    ))
+    .id()
 }
 
+// Additional ball of a multiball, which leaves the table after its time
+#[derive(Component)]
+pub struct ExtraBall(pub Timer);
+
 #[derive(Message)]
 pub struct OnBallDespawnEvent;
 
@@ -86,24 +98,61 @@ fn ball_reset_system(
     mut cmds: Commands,
     mut evw: MessageWriter<OnBallDespawnEvent>,
     mut health_ev: MessageWriter<ChangeHealthEvent>,
-    q_ball: Query<(Entity, &Transform), With<PinBall>>,
-    q_life_bar: Query<Entity, With<LifeBar>>,
+    mut q_ball: Query<(Entity, &mut Transform, &mut LinearVelocity), With<PinBall>>,
+    mut ball_save: ResMut<BallSave>,
+    q_building: QueryBuildings,
+    ball_spawn: Res<BallSpawn>,
 ) {
-    for (entity, transform) in q_ball.iter() {
+    let mut balls_left = q_ball.iter().count();
+    for (entity, mut transform, mut velocity) in q_ball.iter_mut() {
         let ball_pos = transform.translation;
         if !X_RANGE.contains(&ball_pos.x) || !Y_RANGE.contains(&ball_pos.y) {
-            if ball_pos.x > 1.2 && HIT_Y_RANGE.contains(&ball_pos.y) {
-                if let Ok(lifebar_id) = q_life_bar.single() {
-                    health_ev.write(ChangeHealthEvent::new(lifebar_id, -5., None));
-                }
+            // Last ball returns to the ball starter for free
+            if balls_left == 1 && ball_save.try_save() {
+                log!("🛟 Ball saved");
+                transform.translation = ball_spawn.0;
+                velocity.0 = Vec2::ZERO;
+                continue;
             }
             log!("🎱 Despawn ball");
             cmds.get_entity(entity).unwrap().despawn();
+            balls_left -= 1;
+            // Only losing the last ball on the table counts
+            if balls_left > 0 {
+                continue;
+            }
+            if ball_pos.x > 1.2 && HIT_Y_RANGE.contains(&ball_pos.y) {
+                if let Some(building_id) = nearest_building(&q_building, ball_pos) {
+                    health_ev.write(ChangeHealthEvent::new(building_id, -5., None));
+                }
+            }
             evw.write(OnBallDespawnEvent);
         }
     }
 }
 
+fn extra_ball_timeout_system(
+    mut cmds: Commands,
+    mut q_extra: Query<(Entity, &mut ExtraBall)>,
+    q_ball: Query<(), With<PinBall>>,
+    time: Res<Time>,
+) {
+    let mut balls_left = q_ball.iter().count();
+    for (ball_id, mut extra) in q_extra.iter_mut() {
+        if !extra.0.tick(time.delta()).is_finished() {
+            continue;
+        }
+        // The last ball on the table stays as normal ball
+        if balls_left > 1 {
+            log!("🎱 Extra ball time is over");
+            cmds.entity(ball_id).despawn();
+            balls_left -= 1;
+        } else {
+            cmds.entity(ball_id).remove::<ExtraBall>();
+        }
+    }
+}
+
 fn clamp_ball_speed_system(mut q_ball: Query<&mut LinearVelocity, With<PinBall>>) {
     for mut velocity in q_ball.iter_mut() {
         let speed = velocity.length();
@@ -126,8 +175,9 @@ fn on_ball_despawn_system(
     }
 }
 
+// Entity, which the ball collided with, and the ball
 #[derive(Message, Debug)]
-pub struct CollisionWithBallEvent(pub Entity);
+pub struct CollisionWithBallEvent(pub Entity, pub Entity);
 
 fn on_collision_with_ball_system(
     coll_ev: MessageReader<CollisionStart>,
@@ -135,8 +185,8 @@ fn on_collision_with_ball_system(
     mut points_ev: MessageWriter<PointsEvent>,
     q_ball: Query<Entity, With<PinBall>>,
 ) {
-    for collidator_id in get_ball_collisions(coll_ev, q_ball) {
-        coll_with_ball_ev.write(CollisionWithBallEvent(collidator_id));
+    for (collidator_id, ball_id) in get_ball_collisions(coll_ev, q_ball) {
+        coll_with_ball_ev.write(CollisionWithBallEvent(collidator_id, ball_id));
         points_ev.write(PointsEvent::BallCollided);
     }
 }
@@ -144,12 +194,12 @@ fn on_collision_with_ball_system(
 fn get_ball_collisions(
     mut evr: MessageReader<CollisionStart>,
     q_ball: Query<Entity, With<PinBall>>,
-) -> Vec<Entity> {
+) -> Vec<(Entity, Entity)> {
     evr.read()
         .filter_map(|ev| match q_ball.contains(ev.collider1) {
-            true => Some(ev.collider1),
+            true => Some((ev.collider2, ev.collider1)),
             false => match q_ball.contains(ev.collider2) {
-                true => Some(ev.collider2),
+                true => Some((ev.collider1, ev.collider2)),
                 false => None,
             },
         })
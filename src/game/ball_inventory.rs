@@ -0,0 +1,127 @@
+use super::analog_counter::AnalogCounterSetEvent;
+use super::audio::SoundEvent;
+use super::ball::OnBallDespawnEvent;
+use super::level::{PointHub, Points};
+use super::power_up::{PowerUp, PowerUpEvent};
+use super::{EventState, GameState};
+use crate::prelude::*;
+
+pub struct BallInventoryPlugin;
+
+impl Plugin for BallInventoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Init), init_resources)
+            .add_systems(
+                Update,
+                (update_ball_counter_system, extra_ball_points_system)
+                    .run_if(in_state(GameState::Ingame)),
+            )
+            .add_systems(
+                Update,
+                (on_extra_ball_power_up_system, on_last_ball_lost_system)
+                    .run_if(in_state(EventState::Active)),
+            );
+    }
+}
+
+const BALLS_PER_GAME: u32 = 3;
+// Counter has only 2 digits
+const MAX_BALLS: u32 = 99;
+// Points between two extra ball awards
+const EXTRA_BALL_POINTS: Points = 25000;
+
+// Balls, which are left for the ball starter
+#[derive(Resource)]
+pub struct BallInventory {
+    balls: u32,
+    next_award: Points,
+}
+
+impl Default for BallInventory {
+    fn default() -> Self {
+        Self {
+            balls: BALLS_PER_GAME,
+            next_award: EXTRA_BALL_POINTS,
+        }
+    }
+}
+
+impl BallInventory {
+    // Returns false, if no ball is left
+    pub fn take(&mut self) -> bool {
+        if self.balls == 0 {
+            return false;
+        }
+        self.balls -= 1;
+        true
+    }
+
+    fn award(&mut self) {
+        log!("🎱 Extra ball awarded");
+        self.balls = (self.balls + 1).min(MAX_BALLS);
+    }
+}
+
+#[derive(Resource)]
+pub struct BallCounterId(pub Entity);
+
+impl Default for BallCounterId {
+    fn default() -> Self {
+        Self(Entity::from_bits(0))
+    }
+}
+
+// Using insert_resource to reset previous resources of same type
+fn init_resources(mut cmds: Commands) {
+    cmds.insert_resource(BallInventory::default());
+}
+
+fn update_ball_counter_system(
+    inventory: Res<BallInventory>,
+    mut ac_set_ev: MessageWriter<AnalogCounterSetEvent>,
+    bc_id: Res<BallCounterId>,
+) {
+    if inventory.is_changed() {
+        ac_set_ev.write(AnalogCounterSetEvent::new(bc_id.0, inventory.balls));
+    }
+}
+
+fn extra_ball_points_system(
+    mut inventory: ResMut<BallInventory>,
+    mut sound_ev: MessageWriter<SoundEvent>,
+    points: Res<PointHub>,
+) {
+    if !points.is_changed() {
+        return;
+    }
+    while points.0 >= inventory.next_award {
+        inventory.next_award += EXTRA_BALL_POINTS;
+        inventory.award();
+        sound_ev.write(SoundEvent::PbMenuActive);
+    }
+}
+
+fn on_extra_ball_power_up_system(
+    mut evr: MessageReader<PowerUpEvent>,
+    mut inventory: ResMut<BallInventory>,
+) {
+    for PowerUpEvent(power_up, _) in evr.read() {
+        if matches!(power_up, PowerUp::ExtraBall) {
+            inventory.award();
+        }
+    }
+}
+
+// Without balls to launch, the game is over
+fn on_last_ball_lost_system(
+    evr: MessageReader<OnBallDespawnEvent>,
+    mut game_state: ResMut<NextState<GameState>>,
+    mut ev_state: ResMut<NextState<EventState>>,
+    inventory: Res<BallInventory>,
+) {
+    if !evr.is_empty() && inventory.balls == 0 {
+        log!("😵 No balls left");
+        game_state.set(GameState::GameOver);
+        ev_state.set(EventState::Inactive);
+    }
+}
@@ -0,0 +1,110 @@
+use super::ball::OnBallDespawnEvent;
+use super::ball_starter::BallStarterFireEndEvent;
+use super::light::FlashLight;
+use super::tilt::TiltMeter;
+use super::{EventState, GameState};
+use crate::prelude::*;
+
+pub struct BallSavePlugin;
+
+impl Plugin for BallSavePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Init), init_resources)
+            .add_systems(
+                Update,
+                (ball_save_window_system, ball_save_lamp_system)
+                    .run_if(in_state(GameState::Ingame)),
+            )
+            .add_systems(
+                Update,
+                (on_ball_launched_system, on_ball_despawn_system)
+                    .run_if(in_state(EventState::Active)),
+            );
+    }
+}
+
+// Seconds after the launch, in which a drained ball is returned
+const WINDOW_SECS: f32 = 8.;
+
+#[derive(Component)]
+pub struct BallSaveLamp;
+
+// Every new ball gets one save, which is active for a short time after the launch
+#[derive(Resource)]
+pub struct BallSave {
+    window: Timer,
+    is_active: bool,
+    is_used: bool,
+}
+
+impl Default for BallSave {
+    fn default() -> Self {
+        Self {
+            window: Timer::from_seconds(WINDOW_SECS, TimerMode::Once),
+            is_active: false,
+            is_used: false,
+        }
+    }
+}
+
+impl BallSave {
+    // Returns true, if the drained ball is saved
+    pub fn try_save(&mut self) -> bool {
+        if !self.is_active {
+            return false;
+        }
+        self.is_active = false;
+        self.is_used = true;
+        true
+    }
+}
+
+// Using insert_resource to reset previous resources of same type
+fn init_resources(mut cmds: Commands) {
+    cmds.insert_resource(BallSave::default());
+}
+
+fn on_ball_launched_system(
+    evr: MessageReader<BallStarterFireEndEvent>,
+    mut ball_save: ResMut<BallSave>,
+) {
+    if !evr.is_empty() && !ball_save.is_used && !ball_save.is_active {
+        log!("🛟 Ball save is active");
+        ball_save.is_active = true;
+        ball_save.window.reset();
+    }
+}
+
+fn ball_save_window_system(mut ball_save: ResMut<BallSave>, tilt: Res<TiltMeter>, time: Res<Time>) {
+    if !ball_save.is_active {
+        return;
+    }
+    // A tilted table loses its ball save, the tilt ends only with a drained ball
+    if tilt.is_tilted() || ball_save.window.tick(time.delta()).is_finished() {
+        log!("🛟 Ball save is over");
+        ball_save.is_active = false;
+    }
+}
+
+// A lost ball gives the next ball a new save
+fn on_ball_despawn_system(evr: MessageReader<OnBallDespawnEvent>, mut ball_save: ResMut<BallSave>) {
+    if !evr.is_empty() {
+        ball_save.is_used = false;
+    }
+}
+
+fn ball_save_lamp_system(
+    mut cmds: Commands,
+    mut q_lamp: Query<(Entity, &mut Visibility, Has<FlashLight>), With<BallSaveLamp>>,
+    ball_save: Res<BallSave>,
+) {
+    if let Ok((lamp_id, mut visi, is_flashing)) = q_lamp.single_mut() {
+        if ball_save.is_active && !is_flashing {
+            *visi = Visibility::Inherited;
+            cmds.entity(lamp_id).insert(FlashLight);
+        } else if !ball_save.is_active && is_flashing {
+            *visi = Visibility::Hidden;
+            cmds.entity(lamp_id).remove::<FlashLight>();
+        }
+    }
+}
@@ -1,9 +1,11 @@
 use super::audio::SoundEvent;
+use super::ball_inventory::BallInventory;
 use super::events::collision::GameLayer;
 use super::{EventState, GameState, KeyboardControls};
 use crate::game::ball::{self, PinBall};
 use crate::prelude::*;
-use bevy::color::palettes::css::GOLD;
+use bevy::color::palettes::css::{GOLD, LIME, RED};
+use bevy::math::primitives::Cuboid;
 
 pub struct BallStarterPlugin;
 
@@ -29,6 +31,10 @@ impl Plugin for BallStarterPlugin {
                 Update,
                 (on_spawn_ball_system).run_if(in_state(EventState::Active)),
             )
+            .add_systems(
+                Update,
+                charge_gauge_system.run_if(in_state(GameState::Ingame)),
+            )
             .add_systems(
                 Update,
                 fire_system
@@ -46,15 +52,26 @@ fn on_spawn_ball_system(
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut evr: MessageReader<SpawnBallEvent>,
     mut sound_ev: MessageWriter<SoundEvent>,
+    mut inventory: ResMut<BallInventory>,
     ball_spawn: Res<BallSpawn>,
 ) {
     for _ in evr.read() {
+        if !inventory.take() {
+            log!("🎱 No ball left to spawn");
+            continue;
+        }
         ball::spawn(&mut cmds, &mut meshes, &mut materials, ball_spawn.0);
         sound_ev.write(SoundEvent::BallSpawn);
     }
 }
 
-pub fn spawn(spawner: &mut ChildSpawnerCommands, pos: Vec3, assets: &PinballDefenseGltfAssets) {
+pub fn spawn(
+    spawner: &mut ChildSpawnerCommands,
+    mats: &mut Assets<StandardMaterial>,
+    meshes: &mut Assets<Mesh>,
+    pos: Vec3,
+    assets: &PinballDefenseGltfAssets,
+) {
     let collider = |p: &mut ChildSpawnerCommands| {
         p.spawn(starter_plate_mesh(assets));
     };
@@ -69,6 +86,7 @@ pub fn spawn(spawner: &mut ChildSpawnerCommands, pos: Vec3, assets: &PinballDefe
             p.spawn(collider_bundle()).with_children(collider);
             p.spawn(starter_spring(assets));
             p.spawn(starter_rod(assets));
+            p.spawn(charge_gauge(mats, meshes));
         });
 }
 
@@ -125,6 +143,61 @@ fn starter_rod(assets: &PinballDefenseGltfAssets) -> impl Bundle {
     )
 }
 
+#[derive(Component)]
+struct ChargeGauge;
+
+const GAUGE_LENGTH: f32 = 0.1;
+const GAUGE_X: f32 = -0.05;
+
+// Bar beside the plate, which grows with the charge
+fn charge_gauge(mats: &mut Assets<StandardMaterial>, meshes: &mut Assets<Mesh>) -> impl Bundle {
+    (
+        Name::new("Charge Gauge"),
+        ChargeGauge,
+        Mesh3d(meshes.add(Mesh::from(Cuboid::new(GAUGE_LENGTH, 0.008, 0.004)))),
+        MeshMaterial3d(mats.add(StandardMaterial {
+            base_color: LIME.into(),
+            emissive: LinearRgba::from(LIME) * 2.,
+            ..default()
+        })),
+        gauge_transform(0.),
+    )
+}
+
+fn gauge_transform(pull_factor: f32) -> Transform {
+    let length = GAUGE_LENGTH * pull_factor.max(0.01);
+    // Grows from the end, which is next to the ball
+    let x = GAUGE_X + (GAUGE_LENGTH - length) / 2.;
+    let y = -PLATE_SIZE.y / 2. - 0.012;
+    Transform::from_xyz(x, y, 0.03).with_scale(Vec3::new(length / GAUGE_LENGTH, 1., 1.))
+}
+
+type QueryChargeGauge<'w, 's, 'a> = Query<
+    'w,
+    's,
+    (&'a mut Transform, &'a MeshMaterial3d<StandardMaterial>),
+    (With<ChargeGauge>, Without<StarterPlate>),
+>;
+
+fn charge_gauge_system(
+    q_plate: Query<&Transform, (With<StarterPlate>, Changed<Transform>)>,
+    mut q_gauge: QueryChargeGauge,
+    mut mats: ResMut<Assets<StandardMaterial>>,
+) {
+    let Ok(plate) = q_plate.single() else {
+        return;
+    };
+    let pull = plate_pull_factor(plate.translation.x);
+    for (mut trans, mat) in q_gauge.iter_mut() {
+        *trans = gauge_transform(pull);
+        if let Some(mat) = mats.get_mut(&mat.0) {
+            let color = LinearRgba::from(LIME).mix(&LinearRgba::from(RED), pull);
+            mat.base_color = color.into();
+            mat.emissive = color * 2.;
+        }
+    }
+}
+
 #[derive(Resource, Default)]
 pub struct BallSpawn(pub Vec3);
 
@@ -0,0 +1,340 @@
+use super::audio::SoundEvent;
+use super::ball::CollisionWithBallEvent;
+use super::drill::spawn_drill;
+use super::events::collision::GameLayer;
+use super::health::{ChangeHealthEvent, Health, HealthEmptyEvent, HealthRecovery};
+use super::level::PointsEvent;
+use super::light::{contact_light_bundle, LightOnCollision};
+use super::power::{spawn_power_plant, Offline, PowerDraw};
+use super::progress::{self, ProgressBarCountUpEvent, ProgressBarFullEvent};
+use super::{EventState, GameState};
+use crate::prelude::*;
+use crate::settings::GraphicsSettings;
+use crate::utils::RelEntity;
+use bevy::color::palettes::css::{CRIMSON, DARK_GOLDENROD, GREEN, LIME, STEEL_BLUE};
+use bevy::math::primitives::{Cylinder, Torus};
+
+pub struct BasePlugin;
+
+impl Plugin for BasePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Init), init_resources)
+            .add_systems(
+                Update,
+                (damage_boost_system).run_if(in_state(GameState::Ingame)),
+            )
+            .add_systems(
+                Update,
+                (
+                    on_building_destroyed_system,
+                    on_rebuild_field_hit_system,
+                    on_rebuild_system,
+                )
+                    .run_if(in_state(EventState::Active)),
+            );
+    }
+}
+
+// Ball hits on a rebuild field, until the building is back
+const REBUILD_HITS: f32 = 5.;
+// Damage factor for all towers, while the booster works
+const BOOST_FACTOR: f32 = 1.25;
+const BOOSTER_POWER_DRAW: f32 = 1.;
+
+// Buildings in the base, the enemies want to destroy
+#[derive(Component)]
+pub struct BaseBuilding;
+
+// Building has no health left and does not work, until it is rebuilt
+#[derive(Component)]
+pub struct Destroyed;
+
+pub(super) fn building_bundle(name: &'static str, pos: Vec3, max_health: f32) -> impl Bundle {
+    (
+        Name::new(name),
+        BaseBuilding,
+        Health::new(max_health),
+        building_recovery(),
+        spatial_from_pos(pos),
+    )
+}
+
+fn building_recovery() -> HealthRecovery {
+    HealthRecovery::new(2., 8.)
+}
+
+pub fn spawn_health_bar(
+    p: &mut ChildSpawnerCommands,
+    assets: &PinballDefenseGltfAssets,
+    mats: &mut Assets<StandardMaterial>,
+    trans: Transform,
+) {
+    let building_id = p.target_entity();
+    let color = Color::srgb_u8(156, 217, 26);
+    progress::spawn(p, assets, mats, building_id, trans, color, 1.);
+}
+
+pub type QueryBuildings<'w, 's, 'a> =
+    Query<'w, 's, (Entity, &'a Transform), (With<BaseBuilding>, Without<Destroyed>)>;
+
+pub fn nearest_building(q_building: &QueryBuildings, pos: Vec3) -> Option<Entity> {
+    q_building
+        .iter()
+        .min_by(|(_, a), (_, b)| {
+            let dist_a = a.translation.distance(pos);
+            dist_a.total_cmp(&b.translation.distance(pos))
+        })
+        .map(|(id, _)| id)
+}
+
+#[derive(Resource)]
+pub struct DamageBoost(pub f32);
+
+// Using insert_resource to reset previous resources of same type
+fn init_resources(mut cmds: Commands) {
+    cmds.insert_resource(DamageBoost(1.));
+}
+
+pub fn spawn_base(
+    p: &mut ChildSpawnerCommands,
+    mats: &mut Assets<StandardMaterial>,
+    meshes: &mut Assets<Mesh>,
+    assets: &PinballDefenseGltfAssets,
+    g_sett: &GraphicsSettings,
+) {
+    let plant_pos = Vec3::new(1.2, -0.25, -0.03);
+    let plant_id = spawn_power_plant(p, mats, meshes, assets, g_sett, plant_pos);
+    let field_pos = Vec3::new(0.5, -0.38, -0.04);
+    spawn_rebuild_field(p, mats, assets, g_sett, plant_id, field_pos, LIME.into());
+
+    let drill_pos = Vec3::new(1.2, 0.2, -0.03);
+    let counter_pos = Vec3::new(1.2, 0.38, 0.01);
+    let drill_id = spawn_drill(p, mats, meshes, assets, drill_pos, counter_pos);
+    let field_pos = Vec3::new(0.5, 0.32, -0.04);
+    spawn_rebuild_field(
+        p,
+        mats,
+        assets,
+        g_sett,
+        drill_id,
+        field_pos,
+        DARK_GOLDENROD.into(),
+    );
+
+    let booster_pos = Vec3::new(1.15, -0.035, -0.03);
+    let booster_id = spawn_damage_booster(p, mats, meshes, assets, booster_pos);
+    let field_pos = Vec3::new(0.38, 0.5, -0.04);
+    spawn_rebuild_field(
+        p,
+        mats,
+        assets,
+        g_sett,
+        booster_id,
+        field_pos,
+        CRIMSON.into(),
+    );
+}
+
+#[derive(Component)]
+pub struct DamageBooster;
+
+fn spawn_damage_booster(
+    spawner: &mut ChildSpawnerCommands,
+    mats: &mut Assets<StandardMaterial>,
+    meshes: &mut Assets<Mesh>,
+    assets: &PinballDefenseGltfAssets,
+    pos: Vec3,
+) -> Entity {
+    spawner
+        .spawn((
+            building_bundle("Damage Booster", pos, 60.),
+            DamageBooster,
+            PowerDraw(BOOSTER_POWER_DRAW),
+        ))
+        .with_children(|p| {
+            p.spawn((
+                Name::new("Damage Booster Pillar"),
+                Mesh3d(meshes.add(Mesh::from(Cylinder::new(0.02, 0.08)))),
+                MeshMaterial3d(mats.add(StandardMaterial {
+                    base_color: STEEL_BLUE.into(),
+                    perceptual_roughness: 0.5,
+                    metallic: 0.8,
+                    ..default()
+                })),
+                Transform::from_xyz(0., 0., 0.04)
+                    .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+            ));
+            p.spawn((
+                Name::new("Damage Booster Ring"),
+                Mesh3d(meshes.add(Mesh::from(Torus::new(0.025, 0.035)))),
+                MeshMaterial3d(mats.add(StandardMaterial {
+                    base_color: CRIMSON.into(),
+                    emissive: LinearRgba::from(CRIMSON) * 4.,
+                    ..default()
+                })),
+                Transform::from_xyz(0., 0., 0.07)
+                    .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+            ));
+            let bar_trans = Transform::from_xyz(-0.05, 0., 0.).with_scale(Vec3::splat(0.5));
+            spawn_health_bar(p, assets, mats, bar_trans);
+        })
+        .id()
+}
+
+type QueryActiveBooster<'w, 's> =
+    Query<'w, 's, (), (With<DamageBooster>, Without<Destroyed>, Without<Offline>)>;
+
+fn damage_boost_system(mut boost: ResMut<DamageBoost>, q_booster: QueryActiveBooster) {
+    boost.0 = match q_booster.is_empty() {
+        true => 1.,
+        false => BOOST_FACTOR,
+    };
+}
+
+// Only visible and hitable, while its building is destroyed
+#[derive(Component)]
+struct RebuildField;
+
+fn spawn_rebuild_field(
+    spawner: &mut ChildSpawnerCommands,
+    mats: &mut Assets<StandardMaterial>,
+    assets: &PinballDefenseGltfAssets,
+    g_sett: &GraphicsSettings,
+    building_id: Entity,
+    pos: Vec3,
+    color: Color,
+) {
+    spawner
+        .spawn((
+            Name::new("Rebuild Field"),
+            RebuildField,
+            RelEntity(building_id),
+            Mesh3d(assets.foundation_ring.clone()),
+            MeshMaterial3d(mats.add(StandardMaterial {
+                base_color: color,
+                perceptual_roughness: 0.4,
+                metallic: 0.4,
+                ..default()
+            })),
+            Transform::from_translation(pos),
+            Visibility::Hidden,
+            Sensor,
+            DebugRender::collider(GREEN.into()),
+            CollisionLayers::new(GameLayer::Tower, GameLayer::Ball),
+            LightOnCollision,
+        ))
+        .with_children(|p| {
+            let field_id = p.target_entity();
+            let bar_trans = Transform::from_translation(Vec3::new(-0.06, 0., 0.));
+            p.spawn(contact_light_bundle(g_sett, color));
+            progress::spawn(p, assets, mats, field_id, bar_trans, color, 0.);
+        });
+}
+
+type QueryBuildingVisi<'w, 's, 'a> =
+    Query<'w, 's, (Entity, &'a mut Visibility), (With<BaseBuilding>, Without<Destroyed>)>;
+
+type QueryRebuildField<'w, 's, 'a> = Query<
+    'w,
+    's,
+    (Entity, &'a RelEntity, &'a mut Visibility),
+    (
+        With<RebuildField>,
+        Without<BaseBuilding>,
+        Without<Destroyed>,
+    ),
+>;
+
+fn on_building_destroyed_system(
+    mut cmds: Commands,
+    mut evr: MessageReader<HealthEmptyEvent>,
+    mut q_building: QueryBuildingVisi,
+    mut q_field: QueryRebuildField,
+    mut game_state: ResMut<NextState<GameState>>,
+    mut ev_state: ResMut<NextState<EventState>>,
+    mut sound_ev: MessageWriter<SoundEvent>,
+) {
+    let mut destroyed: Vec<Entity> = Vec::new();
+    for ev in evr.read() {
+        let Ok((building_id, mut visi)) = q_building.get_mut(ev.0) else {
+            continue;
+        };
+        log!("💥 Base building {building_id:?} destroyed");
+        *visi = Visibility::Hidden;
+        cmds.entity(building_id)
+            .insert(Destroyed)
+            .remove::<HealthRecovery>();
+        if let Some((field_id, _, mut field_visi)) = q_field
+            .iter_mut()
+            .find(|(_, rel_id, _)| rel_id.0 == building_id)
+        {
+            *field_visi = Visibility::Inherited;
+            cmds.entity(field_id).insert(Collider::circle(0.07));
+        }
+        sound_ev.write(SoundEvent::EnemyReachEnd);
+        destroyed.push(building_id);
+    }
+
+    // Lost, if no building is left
+    if !destroyed.is_empty()
+        && q_building
+            .iter()
+            .all(|(building_id, _)| destroyed.contains(&building_id))
+    {
+        log!("💀 Game Over");
+        game_state.set(GameState::GameOver);
+        ev_state.set(EventState::Inactive);
+    }
+}
+
+fn on_rebuild_field_hit_system(
+    mut evr: MessageReader<CollisionWithBallEvent>,
+    mut prog_bar_ev: MessageWriter<ProgressBarCountUpEvent>,
+    mut points_ev: MessageWriter<PointsEvent>,
+    mut sound_ev: MessageWriter<SoundEvent>,
+    q_field: Query<(), (With<RebuildField>, With<Collider>)>,
+) {
+    for CollisionWithBallEvent(id, _) in evr.read() {
+        if q_field.contains(*id) {
+            prog_bar_ev.write(ProgressBarCountUpEvent::new(*id, 1. / REBUILD_HITS));
+            points_ev.write(PointsEvent::FoundationHit);
+            sound_ev.write(SoundEvent::BallHitsFoundation);
+        }
+    }
+}
+
+fn on_rebuild_system(
+    mut cmds: Commands,
+    mut evr: MessageReader<ProgressBarFullEvent>,
+    mut q_field: QueryRebuildField,
+    mut q_building: Query<(&Health, &mut Visibility), With<Destroyed>>,
+    mut prog_bar_ev: MessageWriter<ProgressBarCountUpEvent>,
+    mut health_ev: MessageWriter<ChangeHealthEvent>,
+    mut points_ev: MessageWriter<PointsEvent>,
+    mut sound_ev: MessageWriter<SoundEvent>,
+) {
+    for ProgressBarFullEvent(field_id) in evr.read() {
+        let Ok((_, rel_id, mut field_visi)) = q_field.get_mut(*field_id) else {
+            continue;
+        };
+        let building_id = rel_id.0;
+        let Ok((health, mut visi)) = q_building.get_mut(building_id) else {
+            continue;
+        };
+        log!("🏗️ Rebuild base building {building_id:?}");
+
+        // Hide field
+        *field_visi = Visibility::Hidden;
+        cmds.entity(*field_id).remove::<Collider>();
+        prog_bar_ev.write(ProgressBarCountUpEvent::new(*field_id, -1.));
+
+        // Bring building back
+        *visi = Visibility::Inherited;
+        cmds.entity(building_id)
+            .remove::<Destroyed>()
+            .insert(building_recovery());
+        health_ev.write(ChangeHealthEvent::new(building_id, health.max(), None));
+        points_ev.write(PointsEvent::TowerBuild);
+        sound_ev.write(SoundEvent::TowerBuild);
+    }
+}
@@ -12,9 +12,13 @@ pub(super) fn follow_ball(
     mut q_cam: Query<&mut Transform, (With<Camera>, Without<PinBall>)>,
     q_ball: Query<&Transform, With<PinBall>>,
 ) {
-    for ball_trans in q_ball.iter() {
-        if let Ok(mut cam) = q_cam.single_mut() {
-            cam.look_at(ball_trans.translation, Vec3::Z);
-        }
+    // Looks at the center of all balls on the table
+    let ball_count = q_ball.iter().count();
+    if ball_count == 0 {
+        return;
+    }
+    let center = q_ball.iter().map(|trans| trans.translation).sum::<Vec3>() / ball_count as f32;
+    if let Ok(mut cam) = q_cam.single_mut() {
+        cam.look_at(center, Vec3::Z);
     }
 }
@@ -1,5 +1,6 @@
 use super::set_flipper_status;
 use crate::game::ball_starter::SpawnBallEvent;
+use crate::game::tilt::NudgeEvent;
 use crate::game::{
     ball_starter::BallStarterState,
     flipper::{FlipperStatus, FlipperType},
@@ -13,6 +14,7 @@ use bevy::input::ButtonState;
 pub(super) fn on_btn_changed(
     mut evr: MessageReader<GamepadButtonChangedEvent>,
     mut spawn_ball_ev: MessageWriter<SpawnBallEvent>,
+    mut nudge_ev: MessageWriter<NudgeEvent>,
     mut ball_starter_state: ResMut<NextState<BallStarterState>>,
     mut q_flipper: Query<(&mut FlipperStatus, &FlipperType)>,
     mut menu_state: ResMut<NextState<MenuState>>,
@@ -26,6 +28,9 @@ pub(super) fn on_btn_changed(
             GamepadButton::East if ev.value > 0. => {
                 spawn_ball_ev.write(SpawnBallEvent);
             }
+            GamepadButton::North if ev.value > 0. => {
+                nudge_ev.write(NudgeEvent);
+            }
             GamepadButton::South => ball_starter_state.set(match ev.value == 0. {
                 true => BallStarterState::Fire,
                 false => BallStarterState::Charge,
@@ -12,6 +12,7 @@ pub struct KeyboardControls {
     pub flipper_left: KeyCode,
     pub flipper_right: KeyCode,
     pub charge_ball_starter: KeyCode,
+    pub nudge: KeyCode,
     pub pause: KeyCode,
     pub toggle_key_ui: KeyCode,
 }
@@ -23,6 +24,7 @@ impl Default for KeyboardControls {
             flipper_left: KeyCode::KeyA,
             flipper_right: KeyCode::KeyD,
             charge_ball_starter: KeyCode::Space,
+            nudge: KeyCode::KeyS,
             pause: KeyCode::KeyP,
             toggle_key_ui: KeyCode::KeyK,
         }
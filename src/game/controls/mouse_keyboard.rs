@@ -2,8 +2,9 @@ use super::{set_flipper_status, KeyboardControls};
 use crate::game::ball_starter::{BallStarterState, SpawnBallEvent};
 use crate::game::camera::CameraState;
 use crate::game::flipper::{FlipperStatus, FlipperType};
+use crate::game::tilt::NudgeEvent;
 use crate::game::ui::UiState;
-use crate::game::{ball, GameState, PauseGameEvent, ResumeGameEvent};
+use crate::game::{GameState, PauseGameEvent, ResumeGameEvent};
 use crate::menu::MenuState;
 use crate::prelude::*;
 use bevy::window::{CursorGrabMode, CursorOptions, PrimaryWindow};
@@ -12,6 +13,7 @@ pub(super) fn key_system(
     key: Res<ButtonInput<KeyCode>>,
     controls: Res<KeyboardControls>,
     mut spawn_ball_ev: MessageWriter<SpawnBallEvent>,
+    mut nudge_ev: MessageWriter<NudgeEvent>,
     mut pause_ev: MessageWriter<PauseGameEvent>,
     mut cam_state: ResMut<NextState<CameraState>>,
     mut ball_starter_state: ResMut<NextState<BallStarterState>>,
@@ -56,6 +58,10 @@ pub(super) fn key_system(
         ball_starter_state.set(BallStarterState::Fire);
     }
 
+    if key.just_pressed(controls.nudge) {
+        nudge_ev.write(NudgeEvent);
+    }
+
     if key.just_pressed(controls.flipper_left) {
         set_flipper_status(FlipperType::Left, FlipperStatus::Pushed, &mut q_flipper);
     }
@@ -97,22 +103,10 @@ pub(super) fn pause_key_system(
 }
 
 pub(super) fn mouse_btn_system(
-    mut cmds: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
     btn: Res<ButtonInput<MouseButton>>,
     mut cam_state: ResMut<NextState<CameraState>>,
     mut cursor_options: Single<&mut CursorOptions, With<PrimaryWindow>>,
 ) {
-    if btn.just_pressed(MouseButton::Left) {
-        ball::spawn(
-            &mut cmds,
-            &mut meshes,
-            &mut materials,
-            Vec3::new(0., 0.2, 0.),
-        );
-    }
-
     if btn.just_pressed(MouseButton::Right) {
         cursor_options.grab_mode = CursorGrabMode::Locked;
         cursor_options.visible = false;
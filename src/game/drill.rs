@@ -0,0 +1,169 @@
+use super::analog_counter::{self, AnalogCounterSetEvent};
+use super::base::{building_bundle, spawn_health_bar, Destroyed};
+use super::cfg::CONFIG;
+use super::enemy::RoadEndReachedEvent;
+use super::power::{Offline, PowerDraw};
+use super::{EventState, GameState};
+use crate::prelude::*;
+use bevy::color::palettes::css::{DARK_GOLDENROD, DIM_GRAY};
+use bevy::math::primitives::{Cone, Cuboid};
+use std::time::Duration;
+
+pub struct DrillPlugin;
+
+impl Plugin for DrillPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<DepositMinedEvent>()
+            .add_systems(
+                Update,
+                (extract_system, drill_bit_animation_system).run_if(in_state(GameState::Ingame)),
+            )
+            .add_systems(
+                Update,
+                (on_attack_system, on_deposit_mined_system).run_if(in_state(EventState::Active)),
+            );
+    }
+}
+
+// Resources per second
+const EXTRACTION_RATE: f32 = 1.;
+const POWER_DRAW: f32 = 1.;
+// The drill stops for this time, if an enemy reaches the base
+const ATTACK_PAUSE_SECS: f32 = 3.;
+
+#[derive(Component)]
+pub struct Drill {
+    deposit: f32,
+    mined: f32,
+    is_running: bool,
+    attack_pause: Timer,
+}
+
+impl Drill {
+    fn new(deposit: f32) -> Self {
+        let mut attack_pause = Timer::from_seconds(ATTACK_PAUSE_SECS, TimerMode::Once);
+        attack_pause.set_elapsed(Duration::from_secs_f32(ATTACK_PAUSE_SECS));
+        Self {
+            deposit,
+            mined: 0.,
+            is_running: false,
+            attack_pause,
+        }
+    }
+}
+
+#[derive(Component)]
+struct DrillBit;
+
+pub fn spawn_drill(
+    spawner: &mut ChildSpawnerCommands,
+    mats: &mut Assets<StandardMaterial>,
+    meshes: &mut Assets<Mesh>,
+    assets: &PinballDefenseGltfAssets,
+    pos: Vec3,
+    counter_pos: Vec3,
+) -> Entity {
+    let drill_id = spawner
+        .spawn((
+            building_bundle("Drill", pos, 100.),
+            Drill::new(CONFIG.drill_deposit),
+            PowerDraw(POWER_DRAW),
+        ))
+        .with_children(|p| {
+            p.spawn((
+                Name::new("Drill Frame"),
+                Mesh3d(meshes.add(Mesh::from(Cuboid::new(0.08, 0.08, 0.1)))),
+                MeshMaterial3d(mats.add(StandardMaterial {
+                    base_color: DIM_GRAY.into(),
+                    perceptual_roughness: 0.8,
+                    metallic: 0.6,
+                    ..default()
+                })),
+                Transform::from_xyz(0., 0., 0.05),
+            ));
+            p.spawn((
+                Name::new("Drill Bit"),
+                Mesh3d(meshes.add(Mesh::from(Cone::new(0.03, 0.06)))),
+                MeshMaterial3d(mats.add(StandardMaterial {
+                    base_color: DARK_GOLDENROD.into(),
+                    perceptual_roughness: 0.3,
+                    metallic: 0.9,
+                    ..default()
+                })),
+                // Cone tip points into the ground
+                Transform::from_xyz(0., 0., 0.11)
+                    .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+                DrillBit,
+            ));
+            let bar_trans = Transform::from_xyz(-0.06, 0., 0.).with_scale(Vec3::splat(0.5));
+            spawn_health_bar(p, assets, mats, bar_trans);
+        })
+        .id();
+    analog_counter::spawn_10_digit(spawner, assets, counter_pos, Some(drill_id));
+    drill_id
+}
+
+#[derive(Message)]
+struct DepositMinedEvent;
+
+fn extract_system(
+    mut q_drill: Query<(Entity, &mut Drill, Has<Offline>, Has<Destroyed>)>,
+    mut ac_set_ev: MessageWriter<AnalogCounterSetEvent>,
+    mut mined_ev: MessageWriter<DepositMinedEvent>,
+    time: Res<Time>,
+) {
+    for (drill_id, mut drill, is_offline, is_destroyed) in q_drill.iter_mut() {
+        drill.attack_pause.tick(time.delta());
+        drill.is_running =
+            !is_offline && !is_destroyed && drill.attack_pause.is_finished() && drill.deposit > 0.;
+        if !drill.is_running {
+            continue;
+        }
+        let amount = (EXTRACTION_RATE * time.delta_secs()).min(drill.deposit);
+        let mined_before = drill.mined as u32;
+        drill.deposit -= amount;
+        drill.mined += amount;
+        if drill.mined as u32 != mined_before {
+            ac_set_ev.write(AnalogCounterSetEvent::new(drill_id, drill.mined as u32));
+        }
+        if drill.deposit <= 0. {
+            log!("⛏️ Deposit fully mined");
+            mined_ev.write(DepositMinedEvent);
+        }
+    }
+}
+
+fn drill_bit_animation_system(
+    mut q_bit: Query<(&mut Transform, &ChildOf), With<DrillBit>>,
+    q_drill: Query<&Drill>,
+    time: Res<Time>,
+) {
+    for (mut trans, child_of) in q_bit.iter_mut() {
+        if q_drill
+            .get(child_of.parent())
+            .is_ok_and(|drill| drill.is_running)
+        {
+            trans.rotate_local_y(time.delta_secs() * 8.);
+        }
+    }
+}
+
+fn on_attack_system(mut evr: MessageReader<RoadEndReachedEvent>, mut q_drill: Query<&mut Drill>) {
+    if evr.read().count() > 0 {
+        for mut drill in q_drill.iter_mut() {
+            drill.attack_pause.reset();
+        }
+    }
+}
+
+fn on_deposit_mined_system(
+    evr: MessageReader<DepositMinedEvent>,
+    mut game_state: ResMut<NextState<GameState>>,
+    mut ev_state: ResMut<NextState<EventState>>,
+) {
+    if !evr.is_empty() {
+        log!("🏆 Victory");
+        game_state.set(GameState::Victory);
+        ev_state.set(EventState::Inactive);
+    }
+}
@@ -0,0 +1,79 @@
+use crate::game::level::PointsEvent;
+use crate::prelude::*;
+use bevy::color::palettes::css::{DARK_RED, ORANGE, RED};
+use serde::Deserialize;
+
+#[derive(Component, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EnemyKind {
+    #[default]
+    Normal,
+    // Slow, much life and armoured
+    Tank,
+    // Very fast, less life
+    Speeder,
+}
+
+impl EnemyKind {
+    pub(super) fn health(&self) -> f32 {
+        match self {
+            EnemyKind::Normal => 100.,
+            EnemyKind::Tank => 400.,
+            EnemyKind::Speeder => 50.,
+        }
+    }
+
+    pub(super) fn speed(&self) -> f32 {
+        match self {
+            EnemyKind::Normal => 0.2,
+            EnemyKind::Tank => 0.1,
+            EnemyKind::Speeder => 0.45,
+        }
+    }
+
+    // Damage to the nearest base building, when the enemy reaches the road end
+    pub(super) fn road_end_damage(&self) -> f32 {
+        match self {
+            EnemyKind::Normal => 10.,
+            EnemyKind::Tank => 25.,
+            EnemyKind::Speeder => 6.,
+        }
+    }
+
+    pub(super) fn died_points(&self) -> PointsEvent {
+        match self {
+            EnemyKind::Normal => PointsEvent::EnemyDied,
+            EnemyKind::Tank => PointsEvent::TankDied,
+            EnemyKind::Speeder => PointsEvent::SpeederDied,
+        }
+    }
+
+    pub(super) fn radius(&self) -> f32 {
+        match self {
+            EnemyKind::Normal => 0.03,
+            EnemyKind::Tank => 0.045,
+            EnemyKind::Speeder => 0.022,
+        }
+    }
+
+    // Darker means more life
+    pub(super) fn color(&self) -> Color {
+        match self {
+            EnemyKind::Normal => RED.into(),
+            EnemyKind::Tank => DARK_RED.into(),
+            EnemyKind::Speeder => ORANGE.into(),
+        }
+    }
+
+    // Armoured enemies are no sensors, so the ball bounces off
+    pub(super) fn is_armoured(&self) -> bool {
+        *self == EnemyKind::Tank
+    }
+
+    // Factor on the damage of a ball hit
+    pub(super) fn ball_damage_factor(&self) -> f32 {
+        match self.is_armoured() {
+            true => 0.5,
+            false => 1.,
+        }
+    }
+}
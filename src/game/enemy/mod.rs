@@ -1,21 +1,22 @@
 use self::step::Step;
-use self::walk::{
-    on_road_end_reached_system, recover_speed_system, walk_system, RoadEndReachedEvent, WALK_SPEED,
-};
+use self::walk::{on_road_end_reached_system, recover_speed_system, walk_system};
 use super::audio::SoundEvent;
 use super::events::collision::GameLayer;
 use super::health::{ChangeHealthEvent, Health, HealthEmptyEvent};
 use super::level::PointsEvent;
 use super::{ui, EventState};
-use crate::game::ball::CollisionWithBallEvent;
+use crate::game::ball::{CollisionWithBallEvent, PinBall};
+use crate::game::road::{RoadGraph, RoadRoute};
 use crate::game::world::QueryWorld;
 use crate::game::GameState;
-use crate::generated::world_1::road_points::ROAD_POINTS;
 use crate::prelude::*;
-use bevy::color::palettes::css::RED;
 use bevy::math::primitives::Sphere;
+pub use kind::EnemyKind;
+use std::ops::RangeInclusive;
 use std::time::Duration;
+pub use walk::RoadEndReachedEvent;
 
+mod kind;
 mod step;
 mod walk;
 
@@ -28,7 +29,8 @@ impl Plugin for EnemyPlugin {
             .add_message::<OnEnemyDespawnEvent>()
             .add_systems(
                 Update,
-                (walk_system, recover_speed_system).run_if(in_state(GameState::Ingame)),
+                (walk_system, recover_speed_system, ball_hit_cooldown_system)
+                    .run_if(in_state(GameState::Ingame)),
             )
             .add_systems(
                 Update,
@@ -45,17 +47,19 @@ impl Plugin for EnemyPlugin {
 
 #[derive(Component)]
 pub struct Enemy {
+    route: RoadRoute,
     step: Step,
     speed: f32,
     current_speed: f32,
 }
 
 impl Enemy {
-    pub fn new() -> Self {
+    pub fn new(route: RoadRoute, speed: f32) -> Self {
         Self {
-            step: Step::new(1),
-            speed: WALK_SPEED,
-            current_speed: WALK_SPEED,
+            step: Step::new(1, &route),
+            route,
+            speed,
+            current_speed: speed,
         }
     }
 
@@ -63,22 +67,47 @@ impl Enemy {
         let distance = dur.as_secs_f32() * self.current_speed;
         let mut new_pos = self.step.walk(current_pos, distance);
         if self.step.is_reached_point() {
-            if self.step.is_reached_road_end() {
+            if self.step.is_reached_road_end(&self.route) {
                 return None;
             }
-            self.step = self.step.next();
-            new_pos = self.step.start_pos();
+            self.step = self.step.next(&self.route);
+            new_pos = self.step.start_pos(&self.route);
         }
         Some(new_pos)
     }
 
+    // Distance along the route until the enemy reaches the road end
+    pub fn remaining_distance(&self) -> f32 {
+        let step_left = (self.step.distance_to_walk - self.step.distance_walked).max(0.);
+        self.route.remaining_dist_from(self.step.i_road_point) + step_left
+    }
+
+    // Movement per second on the current part of the route
+    pub fn velocity(&self) -> Vec3 {
+        self.step.direction * self.current_speed
+    }
+
+    pub fn is_slowed(&self) -> bool {
+        self.current_speed < self.speed
+    }
+
     pub fn slow_down(&mut self, factor: f32) {
         self.current_speed = self.speed * factor;
     }
 }
 
 #[derive(Message)]
-pub struct SpawnEnemyEvent;
+pub struct SpawnEnemyEvent {
+    kind: EnemyKind,
+    // Index of the road portal, random if none
+    portal: Option<usize>,
+}
+
+impl SpawnEnemyEvent {
+    pub fn new(kind: EnemyKind, portal: Option<usize>) -> Self {
+        Self { kind, portal }
+    }
+}
 
 fn on_spawn_system(
     mut cmds: Commands,
@@ -86,15 +115,22 @@ fn on_spawn_system(
     mut meshes: ResMut<Assets<Mesh>>,
     mut mats: ResMut<Assets<StandardMaterial>>,
     q_pqw: QueryWorld,
+    road: Res<RoadGraph>,
 ) {
-    for _ in evr.read() {
+    for ev in evr.read() {
+        let kind = ev.kind;
+        let route = road.route(ev.portal);
         let mut enemy_id: Option<Entity> = None;
         let Ok(world) = q_pqw.single() else {
             warn!("[enemy spawn] no world");
             return;
         };
         cmds.entity(world).with_children(|spawner| {
-            enemy_id = Some(spawner.spawn(enemy(&mut meshes, &mut mats)).id());
+            let mut enemy_cmds = spawner.spawn(enemy(kind, route, &mut meshes, &mut mats));
+            if !kind.is_armoured() {
+                enemy_cmds.insert(Sensor);
+            }
+            enemy_id = Some(enemy_cmds.id());
         });
         if let Some(enemy_id) = enemy_id {
             ui::progress_bar::spawn(&mut cmds, enemy_id, 1.);
@@ -105,28 +141,31 @@ fn on_spawn_system(
 #[derive(Component)]
 pub struct LastDamager(pub Option<Entity>);
 
-fn enemy(meshes: &mut Assets<Mesh>, mats: &mut Assets<StandardMaterial>) -> impl Bundle {
+fn enemy(
+    kind: EnemyKind,
+    route: RoadRoute,
+    meshes: &mut Assets<Mesh>,
+    mats: &mut Assets<StandardMaterial>,
+) -> impl Bundle {
+    let radius = kind.radius();
     (
-        Name::new("Enemy"),
-        Enemy::new(),
-        Health::new(100.),
+        Name::new(format!("Enemy {kind:?}")),
+        Transform::from_translation(route.start()),
+        Enemy::new(route, kind.speed()),
+        kind,
+        Health::new(kind.health()),
         LastDamager(None),
-        Mesh3d(meshes.add(Mesh::from(Sphere {
-            radius: 0.03,
-            ..default()
-        }))),
+        Mesh3d(meshes.add(Mesh::from(Sphere { radius }))),
         MeshMaterial3d(mats.add(StandardMaterial {
-            base_color: RED.into(),
+            base_color: kind.color(),
             perceptual_roughness: 0.,
             metallic: 1.,
             reflectance: 1.,
             ..default()
         })),
-        Transform::from_translation(ROAD_POINTS[0]),
-        Sensor,
         RigidBody::Kinematic,
-        Collider::circle(0.03),
-        DebugRender::default().with_collider_color(RED.into()),
+        Collider::circle(radius),
+        DebugRender::default().with_collider_color(kind.color()),
         CollisionLayers::new(GameLayer::Enemy, GameLayer::Ball),
         Restitution {
             coefficient: 2.,
@@ -135,21 +174,59 @@ fn enemy(meshes: &mut Assets<Mesh>, mats: &mut Assets<StandardMaterial>) -> impl
     )
 }
 
+// Damage per ball speed at the contact
+const BALL_DAMAGE_PER_SPEED: f32 = 15.;
+const BALL_DAMAGE_RANGE: RangeInclusive<f32> = 20.0..=200.0;
+// Seconds, in which further contacts with the same enemy are ignored
+const BALL_HIT_COOLDOWN_SECS: f32 = 0.3;
+
+#[derive(Component)]
+struct BallHitCooldown(Timer);
+
+fn ball_hit_cooldown_system(
+    mut cmds: Commands,
+    mut q_cooldown: Query<(Entity, &mut BallHitCooldown)>,
+    time: Res<Time>,
+) {
+    for (enemy_id, mut cooldown) in q_cooldown.iter_mut() {
+        if cooldown.0.tick(time.delta()).is_finished() {
+            cmds.entity(enemy_id).try_remove::<BallHitCooldown>();
+        }
+    }
+}
+
 fn on_pinball_hit_system(
+    mut cmds: Commands,
     mut evr: MessageReader<CollisionWithBallEvent>,
     mut points_ev: MessageWriter<PointsEvent>,
     mut sound_ev: MessageWriter<SoundEvent>,
     mut health_ev: MessageWriter<ChangeHealthEvent>,
-    q_enemy: Query<Entity, With<Enemy>>,
+    q_enemy: Query<&EnemyKind, (With<Enemy>, Without<BallHitCooldown>)>,
+    q_ball: Query<&LinearVelocity, With<PinBall>>,
 ) {
-    for CollisionWithBallEvent(id) in evr.read() {
-        // flag == CollisionEventFlags::SENSOR &&
-        if q_enemy.contains(*id) {
-            log!("😵 Pinball hits enemy {:?}", *id);
-            health_ev.write(ChangeHealthEvent::new(*id, -100., None));
-            points_ev.write(PointsEvent::BallEnemyHit);
-            sound_ev.write(SoundEvent::BallHitsEnemy);
+    let mut hit_ids: Vec<Entity> = Vec::new();
+    for CollisionWithBallEvent(id, ball_id) in evr.read() {
+        let Ok(kind) = q_enemy.get(*id) else {
+            continue;
+        };
+        // One pass of the ball counts only once
+        if hit_ids.contains(id) {
+            continue;
         }
+        hit_ids.push(*id);
+        let speed = q_ball.get(*ball_id).map_or(0., |vel| vel.length());
+        let damage = (speed * BALL_DAMAGE_PER_SPEED)
+            .clamp(*BALL_DAMAGE_RANGE.start(), *BALL_DAMAGE_RANGE.end())
+            * kind.ball_damage_factor();
+        log!("😵 Pinball hits enemy {id:?} with speed {speed:.2}, damage {damage:.0}");
+        health_ev.write(ChangeHealthEvent::new(*id, -damage, None));
+        points_ev.write(PointsEvent::BallEnemyHit);
+        sound_ev.write(SoundEvent::BallHitsEnemy);
+        cmds.entity(*id)
+            .try_insert(BallHitCooldown(Timer::from_seconds(
+                BALL_HIT_COOLDOWN_SECS,
+                TimerMode::Once,
+            )));
     }
 }
 
@@ -161,13 +238,13 @@ fn on_health_empty_system(
     mut evr: MessageReader<HealthEmptyEvent>,
     mut despawn_ev: MessageWriter<OnEnemyDespawnEvent>,
     mut points_ev: MessageWriter<PointsEvent>,
-    q_enemy: Query<Entity, With<Enemy>>,
+    q_enemy: Query<&EnemyKind, With<Enemy>>,
 ) {
     for ev in evr.read() {
-        if q_enemy.contains(ev.0) {
+        if let Ok(kind) = q_enemy.get(ev.0) {
             cmds.entity(ev.0).despawn();
             despawn_ev.write(OnEnemyDespawnEvent(ev.0));
-            points_ev.write(PointsEvent::EnemyDied);
+            points_ev.write(kind.died_points());
         }
     }
 }
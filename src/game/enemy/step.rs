@@ -1,4 +1,4 @@
-use crate::generated::world_1::road_points::{ROAD_DISTS, ROAD_POINTS};
+use crate::game::road::RoadRoute;
 use crate::prelude::*;
 
 pub(super) struct Step {
@@ -9,18 +9,18 @@ pub(super) struct Step {
 }
 
 impl Step {
-    pub fn new(i_point: usize) -> Self {
-        let dir = get_direction_to(i_point);
+    pub fn new(i_point: usize, route: &RoadRoute) -> Self {
+        let dir = get_direction_to(i_point, route);
         Self {
             i_road_point: i_point,
-            distance_to_walk: ROAD_DISTS[i_point - 1],
+            distance_to_walk: route.dist_to(i_point),
             distance_walked: 0.,
-            direction: dir.normalize(),
+            direction: dir.normalize_or_zero(),
         }
     }
 
-    pub fn next(&self) -> Self {
-        Self::new(self.i_road_point + 1)
+    pub fn next(&self, route: &RoadRoute) -> Self {
+        Self::new(self.i_road_point + 1, route)
     }
 
     pub fn walk(&mut self, current_pos: Vec3, distance: f32) -> Vec3 {
@@ -28,19 +28,19 @@ impl Step {
         current_pos + self.direction * distance
     }
 
-    pub fn start_pos(&self) -> Vec3 {
-        ROAD_POINTS[self.i_road_point - 1]
+    pub fn start_pos(&self, route: &RoadRoute) -> Vec3 {
+        route.point(self.i_road_point - 1)
     }
 
     pub fn is_reached_point(&self) -> bool {
         self.distance_walked >= self.distance_to_walk
     }
 
-    pub fn is_reached_road_end(&self) -> bool {
-        self.i_road_point == ROAD_POINTS.len() - 1 && self.is_reached_point()
+    pub fn is_reached_road_end(&self, route: &RoadRoute) -> bool {
+        route.is_last_point(self.i_road_point) && self.is_reached_point()
     }
 }
 
-fn get_direction_to(i: usize) -> Vec3 {
-    ROAD_POINTS[i] - ROAD_POINTS[i - 1]
+fn get_direction_to(i: usize, route: &RoadRoute) -> Vec3 {
+    route.point(i) - route.point(i - 1)
 }
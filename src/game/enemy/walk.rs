@@ -1,23 +1,24 @@
-use super::Enemy;
+use super::{Enemy, EnemyKind};
 use crate::game::audio::SoundEvent;
+use crate::game::base::{nearest_building, QueryBuildings};
 use crate::game::health::ChangeHealthEvent;
-use crate::game::player_life::LifeBar;
 use crate::prelude::*;
 
-pub(super) const WALK_SPEED: f32 = 0.2;
-
 pub(super) fn walk_system(
     mut cmds: Commands,
-    mut q_enemy: Query<(Entity, &mut Transform, &mut Enemy)>,
+    mut q_enemy: Query<(Entity, &mut Transform, &mut Enemy, &EnemyKind)>,
     mut end_reached_ev: MessageWriter<RoadEndReachedEvent>,
     time: Res<Time>,
 ) {
-    for (enemy_id, mut trans, mut enemy) in q_enemy.iter_mut() {
+    for (enemy_id, mut trans, mut enemy, kind) in q_enemy.iter_mut() {
         match enemy.walk(trans.translation, time.delta()) {
             Some(pos) => trans.translation = pos,
             None => {
                 // Reminder: If you need infos about the enemy, overgive only infos, not enemy id
-                end_reached_ev.write(RoadEndReachedEvent);
+                end_reached_ev.write(RoadEndReachedEvent {
+                    damage: kind.road_end_damage(),
+                    pos: trans.translation,
+                });
 
                 // Delete enemy here, to prevent double events
                 cmds.entity(enemy_id).despawn();
@@ -27,18 +28,22 @@ pub(super) fn walk_system(
 }
 
 #[derive(Message)]
-pub(super) struct RoadEndReachedEvent;
+pub struct RoadEndReachedEvent {
+    damage: f32,
+    pos: Vec3,
+}
 
 pub(super) fn on_road_end_reached_system(
     mut evr: MessageReader<RoadEndReachedEvent>,
     mut health_ev: MessageWriter<ChangeHealthEvent>,
     mut sound_ev: MessageWriter<SoundEvent>,
-    q_life_bar: Query<Entity, With<LifeBar>>,
+    q_building: QueryBuildings,
 ) {
-    for _ in evr.read() {
+    for ev in evr.read() {
         log!("🔚 Enemy reached road end");
-        if let Ok(lifebar_id) = q_life_bar.single() {
-            health_ev.write(ChangeHealthEvent::new(lifebar_id, -10., None));
+        // The enemy attacks the nearest building of the base
+        if let Some(building_id) = nearest_building(&q_building, ev.pos) {
+            health_ev.write(ChangeHealthEvent::new(building_id, -ev.damage, None));
             sound_ev.write(SoundEvent::EnemyReachEnd);
         }
     }
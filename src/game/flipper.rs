@@ -2,8 +2,10 @@ use super::audio::SoundEvent;
 use super::ball::CollisionWithBallEvent;
 use super::events::collision::GameLayer;
 use super::level::PointsEvent;
+use super::tilt::TiltMeter;
 use super::{EventState, GameState};
 use crate::prelude::*;
+use serde::Deserialize;
 use std::f32::consts::PI;
 
 pub struct FlipperPlugin;
@@ -11,9 +13,10 @@ pub struct FlipperPlugin;
 impl Plugin for FlipperPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
-            Update,
-            (flipper_system, sound_system).run_if(in_state(GameState::Ingame)),
+            FixedUpdate,
+            flipper_system.run_if(in_state(GameState::Ingame)),
         )
+        .add_systems(Update, sound_system.run_if(in_state(GameState::Ingame)))
         .add_systems(
             Update,
             (on_collision_with_ball_system).run_if(in_state(EventState::Active)),
@@ -21,21 +24,37 @@ impl Plugin for FlipperPlugin {
     }
 }
 
-#[derive(Component)]
-struct Flipper {
-    curr_angle: f32,
-    acceleration_factor: f32,
+// Drive of a flipper, set per flipper in the table layout
+#[derive(Component, Deserialize, Clone, Copy)]
+#[serde(default)]
+pub struct FlipperConfig {
+    // Angular acceleration by the coil in radians per second²
+    torque: f32,
+    // Angular acceleration back to the rest angle per radian of lift
+    return_spring: f32,
+    // Radians between rest and full lift
+    max_angle: f32,
+    // Restitution of the flipper rubber
+    elasticity: f32,
 }
 
-impl Flipper {
-    pub fn new() -> Self {
+impl Default for FlipperConfig {
+    fn default() -> Self {
         Self {
-            curr_angle: 0.,
-            acceleration_factor: 1.,
+            torque: 260.,
+            return_spring: 170.,
+            max_angle: 0.8,
+            elasticity: 0.1,
         }
     }
 }
 
+#[derive(Component, Default)]
+struct Flipper {
+    // Radians per second, integrated on the physics schedule
+    velocity: f32,
+}
+
 #[derive(Component, Debug, PartialEq, Eq)]
 pub enum FlipperType {
     Left = 1,
@@ -77,16 +96,18 @@ pub fn spawn_right(
     transform: Transform,
     spawner: &mut ChildSpawnerCommands,
     assets: &PinballDefenseGltfAssets,
+    config: FlipperConfig,
 ) {
-    spawn(FlipperType::Right, transform, spawner, assets);
+    spawn(FlipperType::Right, transform, spawner, assets, config);
 }
 
 pub fn spawn_left(
     transform: Transform,
     spawner: &mut ChildSpawnerCommands,
     assets: &PinballDefenseGltfAssets,
+    config: FlipperConfig,
 ) {
-    spawn(FlipperType::Left, transform, spawner, assets);
+    spawn(FlipperType::Left, transform, spawner, assets, config);
 }
 
 #[derive(Component)]
@@ -97,12 +118,15 @@ fn spawn(
     transform: Transform,
     spawner: &mut ChildSpawnerCommands,
     assets: &PinballDefenseGltfAssets,
+    config: FlipperConfig,
 ) {
     let sig = flipper_type.signum();
+    // Flipper starts at its rest angle
+    let transform = transform.with_rotation(Quat::from_rotation_z(sig * config.max_angle / 2.));
     spawner
-        .spawn(flipper(flipper_type, assets, transform))
+        .spawn(flipper(flipper_type, assets, transform, config))
         .with_children(|spawner| {
-            spawner.spawn(collider(sig));
+            spawner.spawn(collider(sig, config.elasticity));
         });
 }
 
@@ -110,6 +134,7 @@ fn flipper(
     flipper_type: FlipperType,
     assets: &PinballDefenseGltfAssets,
     transform: Transform,
+    config: FlipperConfig,
 ) -> impl Bundle {
     (
         Mesh3d(match flipper_type {
@@ -118,24 +143,30 @@ fn flipper(
         }),
         MeshMaterial3d(assets.flipper_material.clone()),
         transform,
-        Flipper::new(),
+        // Moved by its angular velocity, so the ball gets the speed of the hit
+        RigidBody::Kinematic,
+        AngularVelocity::default(),
+        // Rotates around the pivot and not around the collider
+        CenterOfMass::ZERO,
+        NoAutoCenterOfMass,
+        Flipper::default(),
+        config,
         Name::new(flipper_type.to_string()),
         FlipperStatus::Idle,
         flipper_type,
     )
 }
 
-fn collider(sig: f32) -> impl Bundle {
+fn collider(sig: f32, elasticity: f32) -> impl Bundle {
     (
         Transform {
             translation: Vec3::new(0.008, sig * -0.115, 0.035),
             rotation: Quat::from_rotation_y(-PI / 2. * 0.85),
             ..default()
         },
-        RigidBody::Kinematic,
         Collider::rectangle(0.06, 0.24),
         Restitution {
-            coefficient: 0.1,
+            coefficient: elasticity,
             combine_rule: CoefficientCombine::Multiply,
         },
         CollisionLayers::new(GameLayer::Map, GameLayer::Ball),
@@ -144,28 +175,42 @@ fn collider(sig: f32) -> impl Bundle {
 }
 
 fn flipper_system(
-    mut q_flipper: Query<(&mut Transform, &FlipperStatus, &mut Flipper, &FlipperType)>,
+    mut q_flipper: Query<(
+        &mut AngularVelocity,
+        &mut Flipper,
+        &Rotation,
+        &FlipperStatus,
+        &FlipperType,
+        &FlipperConfig,
+    )>,
+    tilt: Res<TiltMeter>,
     time: Res<Time>,
 ) {
-    let time = time.delta_secs();
-    for (mut transform, status, mut flipper, f_type) in q_flipper.iter_mut() {
-        let mut change_angle = f_type.signum();
-        match status {
-            FlipperStatus::Idle => {
-                flipper.acceleration_factor = 1.;
-                change_angle *= 8. * time;
-            }
-            FlipperStatus::Pushed => {
-                change_angle *= -time * flipper.acceleration_factor;
-                flipper.acceleration_factor += time * 256.;
-            }
+    let dt = time.delta_secs();
+    if dt <= 0. {
+        return;
+    }
+    for (mut ang_vel, mut flipper, rotation, status, f_type, cfg) in q_flipper.iter_mut() {
+        let rest_angle = f_type.signum() * cfg.max_angle / 2.;
+        let up = -f_type.signum();
+        let angle = rotation.as_radians();
+        // Flippers fall back, while the table is tilted
+        let is_pushed = matches!(status, FlipperStatus::Pushed) && !tilt.is_tilted();
+        let lift = (angle - rest_angle) * up;
+        let mut accel = -up * cfg.return_spring * lift;
+        if is_pushed {
+            accel += up * cfg.torque;
+        }
+        flipper.velocity += accel * dt;
+
+        // Flipper stops at rest and at full lift
+        let next_lift = lift + flipper.velocity * up * dt;
+        ang_vel.0 = flipper.velocity;
+        if !(0. ..=cfg.max_angle).contains(&next_lift) {
+            let stop_lift = next_lift.clamp(0., cfg.max_angle);
+            ang_vel.0 = (stop_lift - lift) * up / dt;
+            flipper.velocity = 0.;
         }
-        let new_angle = flipper.curr_angle + change_angle;
-        let new_clamped_angle = new_angle.clamp(-0.4, 0.4);
-        let pivot_rotation = Quat::from_rotation_z(new_clamped_angle - flipper.curr_angle);
-        let translation = transform.translation;
-        transform.rotate_around(translation, pivot_rotation);
-        flipper.curr_angle = new_clamped_angle;
     }
 }
 
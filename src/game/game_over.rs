@@ -3,10 +3,18 @@ use crate::prelude::*;
 use crate::utils::GameColor;
 use bevy::color::palettes::css::GOLD;
 
-pub fn spawn(mut cmds: Commands, assets: Res<PinballDefenseAssets>) {
+pub fn spawn(cmds: Commands, assets: Res<PinballDefenseAssets>) {
+    spawn_screen(cmds, &assets, "GAME OVER");
+}
+
+pub fn spawn_victory(cmds: Commands, assets: Res<PinballDefenseAssets>) {
+    spawn_screen(cmds, &assets, "VICTORY");
+}
+
+fn spawn_screen(mut cmds: Commands, assets: &PinballDefenseAssets, text: &str) {
     cmds.spawn(container()).with_children(|p| {
-        p.spawn(headline("GAME OVER", &assets));
-        spawn_restart_btn(p, &assets);
+        p.spawn(headline(text, assets));
+        spawn_restart_btn(p, assets);
     });
 }
 
@@ -32,6 +32,14 @@ impl Health {
         Self { current: max, max }
     }
 
+    pub fn current(&self) -> f32 {
+        self.current
+    }
+
+    pub fn max(&self) -> f32 {
+        self.max
+    }
+
     pub fn to_progress(&self, amount: f32) -> PercentBw0And1 {
         amount / self.max
     }
@@ -1,6 +1,8 @@
 use super::{
     analog_counter::AnalogCounterSetEvent,
-    light::{FlashLight, LevelUpLamp},
+    ball::OnBallDespawnEvent,
+    enemy::OnEnemyDespawnEvent,
+    light::{ComboLamp, FlashLight, LevelUpLamp},
     EventState, GameState,
 };
 use crate::prelude::*;
@@ -20,12 +22,20 @@ impl Plugin for LevelPlugin {
                     update_points_counter_system,
                     update_level_counter_system,
                     level_up_animation_system,
+                    update_combo_counter_system,
+                    combo_lamp_system,
                 )
                     .run_if(in_state(GameState::Ingame)),
             )
             .add_systems(
                 Update,
-                (on_add_points_system, on_level_up_lamp).run_if(in_state(EventState::Active)),
+                (
+                    on_add_points_system,
+                    on_level_up_lamp,
+                    on_combo_hit_system,
+                    on_combo_reset_system,
+                )
+                    .run_if(in_state(EventState::Active)),
             );
     }
 }
@@ -34,6 +44,7 @@ impl Plugin for LevelPlugin {
 fn init_resources(mut cmds: Commands) {
     cmds.insert_resource(PointHub::default());
     cmds.insert_resource(LevelHub::default());
+    cmds.insert_resource(ComboHub::default());
 }
 
 #[derive(Message, Clone, Copy)]
@@ -41,18 +52,46 @@ fn init_resources(mut cmds: Commands) {
 pub enum PointsEvent {
     BallCollided = 1,
     FlipperHit = 2,
+    SpinnerRevolution = 5,
     FoundationHit = 10,
     BallEnemyHit = 15,
     TowerHit = 20,
+    SlingshotHit = 30,
+    DropTargetHit = 40,
+    BumperHit = 50,
+    SpeederDied = 60,
     EnemyDied = 85,
+    SaucerCapture = 150,
+    TankDied = 200,
+    PowerUpHit = 250,
+    TowerRecycle = 300,
+    DropBankComplete = 400,
     TowerUpgrade = 500,
     TowerBuild = 1000,
+    SkillShot = 2500,
 }
 
 impl PointsEvent {
     fn points(&self) -> Points {
         *self as Points
     }
+
+    // Ball hits a scoring element, walls and flippers don't count
+    fn is_combo_hit(&self) -> bool {
+        matches!(
+            self,
+            Self::SpinnerRevolution
+                | Self::FoundationHit
+                | Self::BallEnemyHit
+                | Self::TowerHit
+                | Self::SlingshotHit
+                | Self::DropTargetHit
+                | Self::BumperHit
+                | Self::SaucerCapture
+                | Self::PowerUpHit
+                | Self::SkillShot
+        )
+    }
 }
 
 #[cfg(debug_assertions)]
@@ -61,9 +100,13 @@ const POINT_FACTOR: u32 = 10;
 #[cfg(not(debug_assertions))]
 const POINT_FACTOR: u32 = 1;
 
-fn on_add_points_system(mut evr: MessageReader<PointsEvent>, mut points: ResMut<PointHub>) {
+fn on_add_points_system(
+    mut evr: MessageReader<PointsEvent>,
+    mut points: ResMut<PointHub>,
+    combo: Res<ComboHub>,
+) {
     for ev in evr.read() {
-        points.0 += ev.points() * POINT_FACTOR;
+        points.0 += ev.points() * POINT_FACTOR * combo.multiplier();
     }
 }
 
@@ -72,7 +115,7 @@ pub type Level = u8;
 
 #[derive(Resource, Default, Reflect)]
 
-struct PointHub(Points);
+pub(super) struct PointHub(pub(super) Points);
 
 #[derive(Resource, Default, Reflect)]
 pub struct LevelHub {
@@ -150,6 +193,85 @@ fn update_level_counter_system(
     }
 }
 
+// Combo hits for every step of the multiplier
+const COMBO_HITS_PER_STEP: u32 = 10;
+const MAX_COMBO_MULTIPLIER: u32 = 5;
+// An enemy kill counts as this many hits
+const KILL_COMBO_HITS: u32 = 5;
+
+// Streak of ball hits and kills, until the ball drains
+#[derive(Resource, Default, Reflect)]
+pub struct ComboHub {
+    hits: u32,
+}
+
+impl ComboHub {
+    pub fn multiplier(&self) -> u32 {
+        (1 + self.hits / COMBO_HITS_PER_STEP).min(MAX_COMBO_MULTIPLIER)
+    }
+}
+
+fn on_combo_hit_system(
+    mut points_evr: MessageReader<PointsEvent>,
+    mut kill_evr: MessageReader<OnEnemyDespawnEvent>,
+    mut combo: ResMut<ComboHub>,
+) {
+    let hits = points_evr.read().filter(|ev| ev.is_combo_hit()).count() as u32
+        + kill_evr.read().count() as u32 * KILL_COMBO_HITS;
+    if hits > 0 {
+        let multiplier = combo.multiplier();
+        combo.hits += hits;
+        if combo.multiplier() != multiplier {
+            log!("🔥 Combo multiplier: {}", combo.multiplier());
+        }
+    }
+}
+
+fn on_combo_reset_system(evr: MessageReader<OnBallDespawnEvent>, mut combo: ResMut<ComboHub>) {
+    if !evr.is_empty() && combo.hits > 0 {
+        log!("🧊 Combo lost after {} hits", combo.hits);
+        combo.hits = 0;
+    }
+}
+
+#[derive(Resource)]
+pub struct ComboCounterId(pub Entity);
+
+impl Default for ComboCounterId {
+    fn default() -> Self {
+        Self(Entity::from_bits(0))
+    }
+}
+
+fn update_combo_counter_system(
+    combo: Res<ComboHub>,
+    mut ac_set_ev: MessageWriter<AnalogCounterSetEvent>,
+    cc_id: Res<ComboCounterId>,
+) {
+    if combo.is_changed() {
+        // Counter has only 2 digits
+        ac_set_ev.write(AnalogCounterSetEvent::new(cc_id.0, combo.hits.min(99)));
+    }
+}
+
+// Flashes, while the combo multiplies the points
+fn combo_lamp_system(
+    mut cmds: Commands,
+    mut q_lamp: Query<(Entity, &mut Visibility, Has<FlashLight>), With<ComboLamp>>,
+    combo: Res<ComboHub>,
+) {
+    let is_active = combo.multiplier() > 1;
+    if let Ok((lamp_id, mut visi, is_flashing)) = q_lamp.single_mut() {
+        if is_active && !is_flashing {
+            *visi = Visibility::Inherited;
+            cmds.entity(lamp_id).insert(FlashLight);
+        } else if !is_active && is_flashing {
+            *visi = Visibility::Hidden;
+            cmds.entity(lamp_id).remove::<FlashLight>();
+        }
+    }
+}
+
 #[derive(Component)]
 struct LevelUpAnimation(Timer);
 
@@ -32,6 +32,9 @@ impl Plugin for LightPlugin {
 #[derive(Component)]
 pub struct LevelUpLamp;
 
+#[derive(Component)]
+pub struct ComboLamp;
+
 #[derive(Component)]
 pub(super) struct ContactLight;
 
@@ -58,7 +61,7 @@ fn on_contact_light_on_system(
     mut q_light: QueryContactLight,
     q_light_on_coll: Query<Entity, With<LightOnCollision>>,
 ) {
-    for CollisionWithBallEvent(id) in evr.read() {
+    for CollisionWithBallEvent(id, _) in evr.read() {
         if q_light_on_coll.contains(*id) {
             light_on_by_parent(*id, &mut q_light);
         }
@@ -117,7 +120,7 @@ pub(super) fn disable_flash_light(
 #[derive(Component)]
 pub(super) struct LightOnCollision;
 
-const LIGHT_INTENSITY: f32 = 48000.;
+pub(super) const LIGHT_INTENSITY: f32 = 48000.;
 
 type QueryContactLight<'w, 's, 'a> = Query<
     'w,
@@ -170,11 +173,13 @@ fn fade_out_spot_light_system(
 #[derive(Component)]
 pub(super) struct SightRadiusLight;
 
+pub(super) const SIGHT_RADIUS_LIGHT_INTENSITY: f32 = 18000.;
+
 pub(super) fn sight_radius_light(range: f32) -> impl Bundle {
     (
         Name::new("Sight Radius Light"),
         SpotLight {
-            intensity: 18000.,
+            intensity: SIGHT_RADIUS_LIGHT_INTENSITY,
             color: ANTIQUE_WHITE.into(),
             shadows_enabled: false,
             radius: 3.,
@@ -2,14 +2,17 @@ use super::ball::{CollisionWithBallEvent, PinBall};
 use super::events::collision::GameLayer;
 use super::level::{Level, LevelUpEvent};
 use super::progress::ProgressBarFullEvent;
-use super::tower::{SpawnTowerEvent, TowerType, TowerUpgrade};
+use super::tower::{SpawnTowerEvent, TowerCatalogue, TowerType, TowerUpgrade, UpgradeTiers};
 use super::world::QueryWorld;
 use super::{EventState, GameState};
 use crate::game::audio::SoundEvent;
 use crate::game::events::tween_completed::AfterTween;
 use crate::prelude::*;
 use crate::settings::GraphicsSettings;
-use bevy::color::palettes::css::{BEIGE, GREEN};
+use bevy::color::palettes::css::{
+    BEIGE, CORNFLOWER_BLUE, CRIMSON, DARK_OLIVEGREEN, DEEP_SKY_BLUE, GOLDENROD, GREEN,
+    MEDIUM_PURPLE, SILVER, TEAL,
+};
 use bevy_tweening::lens::TransformRotateZLens;
 use bevy_tweening::{Delay, Sequence, Tween, TweenAnim};
 use std::time::Duration;
@@ -85,9 +88,16 @@ impl UpgradeMenuExecuteEvent {
 
 // --- Private Area ---
 
-fn init_resources(mut cmds: Commands) {
-    cmds.insert_resource(UnlockedTowers::default());
+fn init_resources(
+    mut cmds: Commands,
+    mut mats: ResMut<Assets<StandardMaterial>>,
+    assets: Res<PinballDefenseAssets>,
+    catalogues: Res<Assets<TowerCatalogue>>,
+) {
+    let catalogue = TowerCatalogue::get(&assets, &catalogues);
+    cmds.insert_resource(UnlockedTowers(catalogue.unlocked_on(0).collect()));
     cmds.insert_resource(UnlockedUpgrades::default());
+    cmds.insert_resource(CardMaterials::new(&mut mats));
 }
 
 #[derive(Component, Debug, Clone, Copy, Default)]
@@ -131,25 +141,37 @@ type QueryPinballMenuElements<'w, 's, 'a> =
 fn spawn_system(
     mut cmds: Commands,
     mut sound_ev: MessageWriter<SoundEvent>,
+    card_mats: Res<CardMaterials>,
     assets: Res<PinballDefenseGltfAssets>,
     q_pbw: QueryWorld,
     q_pb_menu: Query<&PinballMenu>,
     g_sett: Res<GraphicsSettings>,
-    q_selected: Query<&PinballMenuTrigger, With<PinballMenuSelected>>,
+    q_selected: Query<(Entity, &PinballMenuTrigger), With<PinballMenuSelected>>,
+    q_tower: QueryTowerUpgrades,
     unlocked_towers: Res<UnlockedTowers>,
     unlocked_tower_upgrades: Res<UnlockedUpgrades>,
 ) {
     if q_pb_menu.is_empty() {
-        if let Ok(trigger) = q_selected.single() {
+        if let Ok((selected_id, trigger)) = q_selected.single() {
             log!("🐢 Spawn {trigger:?} menu");
             if let Ok(world_id) = q_pbw.single() {
                 cmds.entity(world_id).with_children(|p| match *trigger {
-                    PinballMenuTrigger::Tower => {
-                        spawn_tower_menu(p, &assets, &g_sett, &unlocked_towers, MENU_POS)
-                    }
-                    PinballMenuTrigger::Upgrade => {
-                        spawn_upgrade_menu(p, &assets, &g_sett, &unlocked_tower_upgrades, MENU_POS)
-                    }
+                    PinballMenuTrigger::Tower => spawn_tower_menu(
+                        p,
+                        &card_mats,
+                        &assets,
+                        &g_sett,
+                        &unlocked_towers,
+                        MENU_POS,
+                    ),
+                    PinballMenuTrigger::Upgrade => spawn_upgrade_menu(
+                        p,
+                        &card_mats,
+                        &assets,
+                        &g_sett,
+                        &available_upgrades(&q_tower, selected_id, &unlocked_tower_upgrades),
+                        MENU_POS,
+                    ),
                 });
                 sound_ev.write(SoundEvent::PbMenuFadeIn);
             }
@@ -165,6 +187,7 @@ enum PinballMenu {
 
 fn spawn_tower_menu(
     spawner: &mut ChildSpawnerCommands,
+    card_mats: &CardMaterials,
     assets: &PinballDefenseGltfAssets,
     g_sett: &GraphicsSettings,
     unlocked_towers: &UnlockedTowers,
@@ -173,7 +196,15 @@ fn spawn_tower_menu(
     spawner.spawn(menu(pos)).with_children(|spawner| {
         let mut angles = CardAngles::new(unlocked_towers.0.len() as u8);
         for tower in unlocked_towers.0.iter() {
-            spawn_menu_element(*tower, spawner, assets, g_sett, angles.next(), 0.1);
+            spawn_menu_element(
+                *tower,
+                spawner,
+                card_mats,
+                assets,
+                g_sett,
+                angles.next(),
+                0.1,
+            );
         }
     });
 }
@@ -189,15 +220,24 @@ fn menu(pos: Vec3) -> impl Bundle {
 
 fn spawn_upgrade_menu(
     spawner: &mut ChildSpawnerCommands,
+    card_mats: &CardMaterials,
     assets: &PinballDefenseGltfAssets,
     g_sett: &GraphicsSettings,
-    unlocked_tower_upgrades: &UnlockedUpgrades,
+    tower_upgrades: &[TowerUpgrade],
     pos: Vec3,
 ) {
     spawner.spawn(menu_element(pos)).with_children(|spawner| {
-        let mut angles = CardAngles::new(unlocked_tower_upgrades.0.len() as u8);
-        for tower_upgrade in unlocked_tower_upgrades.0.iter() {
-            spawn_menu_element(*tower_upgrade, spawner, assets, g_sett, angles.next(), 0.1);
+        let mut angles = CardAngles::new(tower_upgrades.len() as u8);
+        for tower_upgrade in tower_upgrades.iter() {
+            spawn_menu_element(
+                *tower_upgrade,
+                spawner,
+                card_mats,
+                assets,
+                g_sett,
+                angles.next(),
+                0.1,
+            );
         }
     });
 }
@@ -221,13 +261,14 @@ struct PinballMenuElementLight;
 fn spawn_menu_element(
     menu_el_type: impl Component + GetMaterial,
     spawner: &mut ChildSpawnerCommands,
+    card_mats: &CardMaterials,
     assets: &PinballDefenseGltfAssets,
     g_sett: &GraphicsSettings,
     angle: f32,
     delay_secs: f32,
 ) {
     spawner
-        .spawn(element_bundle(menu_el_type, assets))
+        .spawn(element_bundle(menu_el_type, card_mats, assets))
         .insert((
             TweenAnim::new(spawn_animation(angle, delay_secs)),
             AfterTween::ActivatePinballMenu,
@@ -239,11 +280,12 @@ fn spawn_menu_element(
 
 fn element_bundle(
     menu_el_type: impl Component + GetMaterial,
+    card_mats: &CardMaterials,
     assets: &PinballDefenseGltfAssets,
 ) -> impl Bundle {
     (
         Mesh3d(assets.pinball_menu_element.clone()),
-        MeshMaterial3d(menu_el_type.get_menu_element_material(assets)),
+        MeshMaterial3d(menu_el_type.get_menu_element_material(assets, card_mats)),
         Transform::from_rotation(Quat::from_rotation_y(ELEM_START_ANGLE)),
         // Game components
         PinballMenuElement,
@@ -420,7 +462,7 @@ fn on_execute_system(
     q_upgrade_menu_els: QueryUpgradeMenuEls,
     q_selected: Query<(Entity, &Transform), With<PinballMenuSelected>>,
 ) {
-    for CollisionWithBallEvent(id) in evr.read() {
+    for CollisionWithBallEvent(id, _) in evr.read() {
         // if *flag == CollisionEventFlags::SENSOR {
         if let Ok(pb_menu) = q_pb_menu.single() {
             match pb_menu {
@@ -488,7 +530,7 @@ pub fn pinball_menu_glass(
 }
 
 #[derive(Component)]
-struct PinballMenuReady;
+pub(crate) struct PinballMenuReady;
 
 fn on_ready_system(
     mut cmds: Commands,
@@ -510,12 +552,19 @@ fn selected_system(
     mut on_sel_ev: MessageWriter<PinballMenuOnSetSelectedEvent>,
     q_ready: Query<(Entity, &PinballMenuTrigger), With<PinballMenuReady>>,
     q_selected: Query<Entity, With<PinballMenuSelected>>,
+    q_tower: QueryTowerUpgrades,
     unlocked_towers: Res<UnlockedTowers>,
     unlocked_tower_upgrades: Res<UnlockedUpgrades>,
 ) {
     if q_selected.is_empty() {
         for (ready_id, trigger) in q_ready.iter() {
-            if is_unlock_available(*trigger, &unlocked_towers, &unlocked_tower_upgrades) {
+            let is_available = match trigger {
+                PinballMenuTrigger::Tower => !unlocked_towers.0.is_empty(),
+                PinballMenuTrigger::Upgrade => {
+                    !available_upgrades(&q_tower, ready_id, &unlocked_tower_upgrades).is_empty()
+                }
+            };
+            if is_available {
                 set_selected(&mut cmds, ready_id);
                 on_sel_ev.write(PinballMenuOnSetSelectedEvent(ready_id));
                 return;
@@ -524,15 +573,18 @@ fn selected_system(
     }
 }
 
-fn is_unlock_available(
-    trigger: PinballMenuTrigger,
-    unlocked_towers: &UnlockedTowers,
+type QueryTowerUpgrades<'w, 's, 'a> = Query<'w, 's, (&'a TowerType, &'a UpgradeTiers)>;
+
+// Unlocked upgrades, which the upgrade tree of the tower allows next
+fn available_upgrades(
+    q_tower: &QueryTowerUpgrades,
+    tower_id: Entity,
     unlocked_tower_upgrades: &UnlockedUpgrades,
-) -> bool {
-    match trigger {
-        PinballMenuTrigger::Tower => !unlocked_towers.0.is_empty(),
-        PinballMenuTrigger::Upgrade => !unlocked_tower_upgrades.0.is_empty(),
-    }
+) -> Vec<TowerUpgrade> {
+    q_tower
+        .get(tower_id)
+        .map(|(tower_type, tiers)| tiers.available(*tower_type, &unlocked_tower_upgrades.0))
+        .unwrap_or_default()
 }
 
 fn set_selected(cmds: &mut Commands, ref_id: Entity) {
@@ -544,42 +596,40 @@ fn set_selected(cmds: &mut Commands, ref_id: Entity) {
 #[derive(Resource)]
 struct UnlockedTowers(Vec<TowerType>);
 
-impl Default for UnlockedTowers {
+#[derive(Resource)]
+struct UnlockedUpgrades(Vec<TowerUpgrade>);
+
+impl Default for UnlockedUpgrades {
     fn default() -> Self {
-        Self(vec![TowerType::Gun])
+        Self(vec![TowerUpgrade::Targeting])
     }
 }
 
-#[derive(Resource, Default)]
-struct UnlockedUpgrades(Vec<TowerUpgrade>);
-
 fn on_unlock_system(
     mut evr: MessageReader<LevelUpEvent>,
     mut towers: ResMut<UnlockedTowers>,
     mut upgrades: ResMut<UnlockedUpgrades>,
+    assets: Res<PinballDefenseAssets>,
+    catalogues: Res<Assets<TowerCatalogue>>,
 ) {
+    let catalogue = TowerCatalogue::get(&assets, &catalogues);
     for ev in evr.read() {
-        if let Some(tower_type) = new_tower_unlock(ev.0) {
-            towers.0.push(tower_type);
-        }
+        towers.0.extend(catalogue.unlocked_on(ev.0));
         if let Some(tower_upgrade) = new_tower_upgrade_unlock(ev.0) {
             upgrades.0.push(tower_upgrade);
         }
     }
 }
 
-fn new_tower_unlock(level: Level) -> Option<TowerType> {
-    match level {
-        3 => Some(TowerType::Tesla),
-        5 => Some(TowerType::Microwave),
-        _ => None,
-    }
-}
-
 fn new_tower_upgrade_unlock(level: Level) -> Option<TowerUpgrade> {
     match level {
         4 => Some(TowerUpgrade::Range),
         6 => Some(TowerUpgrade::Damage),
+        8 => Some(TowerUpgrade::RotationSpeed),
+        9 => Some(TowerUpgrade::SlowStrength),
+        10 => Some(TowerUpgrade::ChainCount),
+        11 => Some(TowerUpgrade::DamageRadius),
+        12 => Some(TowerUpgrade::FireRate),
         _ => None,
     }
 }
@@ -588,6 +638,7 @@ trait GetMaterial {
     fn get_menu_element_material(
         &self,
         assets: &PinballDefenseGltfAssets,
+        card_mats: &CardMaterials,
     ) -> Handle<StandardMaterial>;
 }
 
@@ -595,23 +646,73 @@ impl GetMaterial for TowerType {
     fn get_menu_element_material(
         &self,
         assets: &PinballDefenseGltfAssets,
+        card_mats: &CardMaterials,
     ) -> Handle<StandardMaterial> {
         match *self {
             TowerType::Gun => assets.pinball_menu_element_gun_material.clone(),
             TowerType::Tesla => assets.pinball_menu_element_tesla_material.clone(),
             TowerType::Microwave => assets.pinball_menu_element_microwave_material.clone(),
+            // No texture in the world gltf yet
+            TowerType::Mortar => card_mats.mortar.clone(),
         }
     }
 }
 
+// Card materials, which aren't in the world gltf yet
+#[derive(Resource)]
+struct CardMaterials {
+    mortar: Handle<StandardMaterial>,
+    targeting: Handle<StandardMaterial>,
+    rotation_speed: Handle<StandardMaterial>,
+    damage_radius: Handle<StandardMaterial>,
+    fire_rate: Handle<StandardMaterial>,
+    slow_strength: Handle<StandardMaterial>,
+    chain_count: Handle<StandardMaterial>,
+    recycle: Handle<StandardMaterial>,
+}
+
+impl CardMaterials {
+    fn new(mats: &mut Assets<StandardMaterial>) -> Self {
+        let mut card_mat = |color| mats.add(card_material(color));
+        Self {
+            mortar: card_mat(DARK_OLIVEGREEN),
+            targeting: card_mat(CORNFLOWER_BLUE),
+            rotation_speed: card_mat(TEAL),
+            damage_radius: card_mat(CRIMSON),
+            fire_rate: card_mat(GOLDENROD),
+            slow_strength: card_mat(MEDIUM_PURPLE),
+            chain_count: card_mat(DEEP_SKY_BLUE),
+            recycle: card_mat(SILVER),
+        }
+    }
+}
+
+fn card_material(color: Srgba) -> StandardMaterial {
+    StandardMaterial {
+        base_color: color.into(),
+        perceptual_roughness: 0.6,
+        metallic: 0.2,
+        ..default()
+    }
+}
+
 impl GetMaterial for TowerUpgrade {
     fn get_menu_element_material(
         &self,
         assets: &PinballDefenseGltfAssets,
+        card_mats: &CardMaterials,
     ) -> Handle<StandardMaterial> {
         match *self {
             TowerUpgrade::Damage => assets.pinball_menu_element_damage_upgrade_mat.clone(),
             TowerUpgrade::Range => assets.pinball_menu_element_range_upgrade_mat.clone(),
+            // No textures in the world gltf yet
+            TowerUpgrade::Targeting => card_mats.targeting.clone(),
+            TowerUpgrade::RotationSpeed => card_mats.rotation_speed.clone(),
+            TowerUpgrade::DamageRadius => card_mats.damage_radius.clone(),
+            TowerUpgrade::FireRate => card_mats.fire_rate.clone(),
+            TowerUpgrade::SlowStrength => card_mats.slow_strength.clone(),
+            TowerUpgrade::ChainCount => card_mats.chain_count.clone(),
+            TowerUpgrade::Recycle => card_mats.recycle.clone(),
         }
     }
 }
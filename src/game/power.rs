@@ -0,0 +1,200 @@
+use super::base::{building_bundle, spawn_health_bar, Destroyed};
+use super::level::LevelUpEvent;
+use super::light::{SightRadiusLight, SIGHT_RADIUS_LIGHT_INTENSITY};
+use super::{EventState, GameState};
+use crate::prelude::*;
+use crate::settings::GraphicsSettings;
+use bevy::color::palettes::css::{DARK_GRAY, LIME, RED};
+use bevy::math::primitives::{Cuboid, Cylinder};
+
+pub struct PowerPlugin;
+
+impl Plugin for PowerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Init), init_resources)
+            .add_systems(
+                Update,
+                (
+                    update_power_grid_system,
+                    dim_offline_lights_system.after(update_power_grid_system),
+                    plant_light_system.after(update_power_grid_system),
+                )
+                    .run_if(in_state(GameState::Ingame)),
+            )
+            .add_systems(
+                Update,
+                (on_level_up_system).run_if(in_state(EventState::Active)),
+            );
+    }
+}
+
+// Capacity of a new power plant
+const PLANT_CAPACITY: f32 = 5.;
+// The plant gets extended on every level up
+const CAPACITY_PER_LEVEL: f32 = 0.5;
+// Factor on the light intensity of offline towers
+const OFFLINE_LIGHT_FACTOR: f32 = 0.15;
+
+#[derive(Component)]
+pub struct PowerPlant {
+    capacity: f32,
+}
+
+// Power, which a building needs to work
+#[derive(Component)]
+pub struct PowerDraw(pub f32);
+
+// Building gets no power from the grid and does not work
+#[derive(Component)]
+pub struct Offline;
+
+// Consumers are supplied in connection order, the newest go offline first
+#[derive(Resource, Default)]
+pub struct PowerGrid {
+    capacity: f32,
+    demand: f32,
+    consumers: Vec<Entity>,
+}
+
+impl PowerGrid {
+    pub fn is_overloaded(&self) -> bool {
+        self.demand > self.capacity
+    }
+}
+
+// Using insert_resource to reset previous resources of same type
+fn init_resources(mut cmds: Commands) {
+    cmds.insert_resource(PowerGrid::default());
+}
+
+#[derive(Component)]
+struct PowerPlantLight;
+
+pub fn spawn_power_plant(
+    spawner: &mut ChildSpawnerCommands,
+    mats: &mut Assets<StandardMaterial>,
+    meshes: &mut Assets<Mesh>,
+    assets: &PinballDefenseGltfAssets,
+    g_sett: &GraphicsSettings,
+    pos: Vec3,
+) -> Entity {
+    let material = mats.add(StandardMaterial {
+        base_color: DARK_GRAY.into(),
+        perceptual_roughness: 0.7,
+        metallic: 0.5,
+        ..default()
+    });
+    spawner
+        .spawn((
+            building_bundle("Power Plant", pos, 100.),
+            PowerPlant {
+                capacity: PLANT_CAPACITY,
+            },
+        ))
+        .with_children(|p| {
+            p.spawn((
+                Name::new("Power Plant Hall"),
+                Mesh3d(meshes.add(Mesh::from(Cuboid::new(0.1, 0.14, 0.04)))),
+                MeshMaterial3d(material.clone()),
+                Transform::from_xyz(0., 0., 0.02),
+            ));
+            p.spawn((
+                Name::new("Power Plant Chimney"),
+                Mesh3d(meshes.add(Mesh::from(Cylinder::new(0.015, 0.08)))),
+                MeshMaterial3d(material),
+                Transform::from_xyz(0.02, 0.04, 0.06)
+                    .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+            ));
+            p.spawn((
+                Name::new("Power Plant Light"),
+                PointLight {
+                    intensity: 4000.,
+                    color: LIME.into(),
+                    shadows_enabled: g_sett.is_shadows,
+                    radius: 0.01,
+                    range: 0.3,
+                    ..default()
+                },
+                Transform::from_xyz(0., 0., 0.08),
+                PowerPlantLight,
+            ));
+            let bar_trans = Transform::from_xyz(-0.07, 0., 0.).with_scale(Vec3::splat(0.5));
+            spawn_health_bar(p, assets, mats, bar_trans);
+        })
+        .id()
+}
+
+fn on_level_up_system(mut evr: MessageReader<LevelUpEvent>, mut q_plant: Query<&mut PowerPlant>) {
+    for _ in evr.read() {
+        for mut plant in q_plant.iter_mut() {
+            plant.capacity += CAPACITY_PER_LEVEL;
+        }
+    }
+}
+
+fn update_power_grid_system(
+    mut cmds: Commands,
+    mut grid: ResMut<PowerGrid>,
+    q_added: Query<Entity, Added<PowerDraw>>,
+    q_consumer: Query<(&PowerDraw, Has<Offline>, Has<Destroyed>)>,
+    q_plant: Query<&PowerPlant, Without<Destroyed>>,
+) {
+    grid.consumers.extend(q_added.iter());
+    grid.consumers.retain(|id| q_consumer.contains(*id));
+    grid.capacity = q_plant.iter().map(|plant| plant.capacity).sum();
+
+    let mut demand = 0.;
+    for id in grid.consumers.iter() {
+        let (draw, is_offline, is_destroyed) =
+            q_consumer.get(*id).expect("😥 Consumer without power draw");
+        // Destroyed buildings need no power
+        if is_destroyed {
+            continue;
+        }
+        demand += draw.0;
+        let is_supplied = demand <= grid.capacity;
+        if is_supplied && is_offline {
+            log!("🔌 Building {id:?} is online");
+            cmds.entity(*id).remove::<Offline>();
+        } else if !is_supplied && !is_offline {
+            log!("🪫 Building {id:?} is offline");
+            cmds.entity(*id).insert(Offline);
+        }
+    }
+    grid.demand = demand;
+}
+
+fn dim_offline_lights_system(
+    mut q_light: Query<(&mut SpotLight, &ChildOf), With<SightRadiusLight>>,
+    mut removed: RemovedComponents<Offline>,
+    q_added: Query<Entity, Added<Offline>>,
+) {
+    let online_ids: Vec<Entity> = removed.read().collect();
+    for (mut light, child_of) in q_light.iter_mut() {
+        if q_added.contains(child_of.parent()) {
+            light.intensity = SIGHT_RADIUS_LIGHT_INTENSITY * OFFLINE_LIGHT_FACTOR;
+        } else if online_ids.contains(&child_of.parent()) {
+            light.intensity = SIGHT_RADIUS_LIGHT_INTENSITY;
+        }
+    }
+}
+
+// Flashes red, while the grid is overloaded
+fn plant_light_system(
+    mut q_light: Query<&mut PointLight, With<PowerPlantLight>>,
+    grid: Res<PowerGrid>,
+    time: Res<Time>,
+) {
+    for mut light in q_light.iter_mut() {
+        match grid.is_overloaded() {
+            true => {
+                light.color = RED.into();
+                light.intensity = ((time.elapsed_secs() * 8.).sin() + 1.) * 4000.;
+            }
+            false => {
+                light.color = LIME.into();
+                light.intensity = 4000.;
+            }
+        }
+    }
+}
@@ -0,0 +1,368 @@
+use super::audio::SoundEvent;
+use super::ball::{self, CollisionWithBallEvent, ExtraBall, PinBall};
+use super::enemy::Enemy;
+use super::events::collision::GameLayer;
+use super::health::ChangeHealthEvent;
+use super::level::PointsEvent;
+use super::world::QueryWorld;
+use super::{EventState, GameState};
+use crate::prelude::*;
+use bevy::color::palettes::css::{
+    BLACK, DODGER_BLUE, GOLD, MEDIUM_PURPLE, ORANGE_RED, SPRING_GREEN,
+};
+use bevy::math::primitives::Cylinder;
+use rand::seq::IndexedRandom;
+
+pub struct PowerUpPlugin;
+
+impl Plugin for PowerUpPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<PowerUpEvent>()
+            .add_systems(OnEnter(GameState::Init), init_resources)
+            .add_systems(
+                Update,
+                (
+                    spawn_target_system,
+                    target_lifetime_system,
+                    ball_power_up_timeout_system,
+                    burning_system,
+                )
+                    .run_if(in_state(GameState::Ingame)),
+            )
+            .add_systems(
+                Update,
+                (
+                    on_target_hit_system,
+                    on_multiball_system,
+                    on_ball_power_up_system,
+                    on_enemy_hit_system,
+                )
+                    .run_if(in_state(EventState::Active)),
+            );
+    }
+}
+
+// Seconds between two power-up targets
+const SPAWN_INTERVAL_SECS: f32 = 30.;
+// Seconds, until an unhit target disappears
+const TARGET_LIFETIME_SECS: f32 = 15.;
+const EXTRA_BALLS: usize = 2;
+// Seconds, until the extra balls leave the table
+const MULTIBALL_SECS: f32 = 20.;
+// Seconds, a ball power-up lasts
+const BALL_POWER_UP_SECS: f32 = 15.;
+const ENLARGED_SCALE: f32 = 2.;
+const AURA_RADIUS: f32 = 0.15;
+const AURA_DAMAGE: f32 = -50.;
+const BURN_SECS: f32 = 4.;
+// Damage per second, while an enemy burns
+const BURN_DAMAGE: f32 = -25.;
+
+// Free places between the tower foundations
+const TARGET_POSIS: [Vec3; 6] = [
+    Vec3::new(-0.6, 0.2, -0.04),
+    Vec3::new(-0.2, -0.35, -0.04),
+    Vec3::new(0.25, 0.3, -0.04),
+    Vec3::new(-0.95, 0.45, -0.04),
+    Vec3::new(0.3, -0.3, -0.04),
+    Vec3::new(-0.4, -0.6, -0.04),
+];
+
+#[derive(Component, Clone, Copy, Debug)]
+pub enum PowerUp {
+    Multiball,
+    Enlarged,
+    DamageAura,
+    FireBall,
+    ExtraBall,
+}
+
+impl PowerUp {
+    const ALL: [PowerUp; 5] = [
+        PowerUp::Multiball,
+        PowerUp::Enlarged,
+        PowerUp::DamageAura,
+        PowerUp::FireBall,
+        PowerUp::ExtraBall,
+    ];
+
+    fn color(&self) -> Color {
+        match self {
+            PowerUp::Multiball => GOLD.into(),
+            PowerUp::Enlarged => DODGER_BLUE.into(),
+            PowerUp::DamageAura => MEDIUM_PURPLE.into(),
+            PowerUp::FireBall => ORANGE_RED.into(),
+            PowerUp::ExtraBall => SPRING_GREEN.into(),
+        }
+    }
+}
+
+#[derive(Message)]
+pub struct PowerUpEvent(pub PowerUp, pub Vec3);
+
+#[derive(Resource)]
+struct PowerUpSpawnTimer(Timer);
+
+// Using insert_resource to reset previous resources of same type
+fn init_resources(mut cmds: Commands) {
+    cmds.insert_resource(PowerUpSpawnTimer(Timer::from_seconds(
+        SPAWN_INTERVAL_SECS,
+        TimerMode::Repeating,
+    )));
+}
+
+#[derive(Component)]
+struct PowerUpTarget {
+    lifetime: Timer,
+}
+
+fn spawn_target_system(
+    mut cmds: Commands,
+    mut timer: ResMut<PowerUpSpawnTimer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut mats: ResMut<Assets<StandardMaterial>>,
+    q_target: Query<(), With<PowerUpTarget>>,
+    q_pbw: QueryWorld,
+    time: Res<Time>,
+) {
+    if !timer.0.tick(time.delta()).just_finished() || !q_target.is_empty() {
+        return;
+    }
+    let mut rng = rand::rng();
+    let (Some(pos), Some(power_up)) =
+        (TARGET_POSIS.choose(&mut rng), PowerUp::ALL.choose(&mut rng))
+    else {
+        return;
+    };
+    if let Ok(world) = q_pbw.single() {
+        log!("🎁 Spawn power-up target {power_up:?}");
+        let color = power_up.color();
+        cmds.entity(world).with_children(|p| {
+            p.spawn((
+                Name::new("Power-Up Target"),
+                Mesh3d(meshes.add(Mesh::from(Cylinder::new(0.03, 0.01)))),
+                MeshMaterial3d(mats.add(StandardMaterial {
+                    base_color: color,
+                    emissive: LinearRgba::from(color) * 8.,
+                    ..default()
+                })),
+                Transform::from_translation(*pos)
+                    .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+                Sensor,
+                Collider::circle(0.03),
+                DebugRender::collider(color),
+                CollisionLayers::new(GameLayer::Tower, GameLayer::Ball),
+                PowerUpTarget {
+                    lifetime: Timer::from_seconds(TARGET_LIFETIME_SECS, TimerMode::Once),
+                },
+                *power_up,
+            ));
+        });
+    }
+}
+
+fn target_lifetime_system(
+    mut cmds: Commands,
+    mut q_target: Query<(Entity, &mut PowerUpTarget)>,
+    time: Res<Time>,
+) {
+    for (target_id, mut target) in q_target.iter_mut() {
+        if target.lifetime.tick(time.delta()).is_finished() {
+            cmds.entity(target_id).despawn();
+        }
+    }
+}
+
+fn on_target_hit_system(
+    mut cmds: Commands,
+    mut evr: MessageReader<CollisionWithBallEvent>,
+    mut power_up_ev: MessageWriter<PowerUpEvent>,
+    mut points_ev: MessageWriter<PointsEvent>,
+    mut sound_ev: MessageWriter<SoundEvent>,
+    q_target: Query<(&PowerUp, &Transform), With<PowerUpTarget>>,
+) {
+    let mut hit_ids: Vec<Entity> = Vec::new();
+    for CollisionWithBallEvent(id, _) in evr.read() {
+        // Target is despawned only after this frame
+        if hit_ids.contains(id) {
+            continue;
+        }
+        if let Ok((power_up, trans)) = q_target.get(*id) {
+            hit_ids.push(*id);
+            log!("🎁 Power-up {power_up:?} hit");
+            power_up_ev.write(PowerUpEvent(*power_up, trans.translation));
+            points_ev.write(PointsEvent::PowerUpHit);
+            sound_ev.write(SoundEvent::PbMenuActive);
+            cmds.entity(*id).try_despawn();
+        }
+    }
+}
+
+fn on_multiball_system(
+    mut cmds: Commands,
+    mut evr: MessageReader<PowerUpEvent>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut mats: ResMut<Assets<StandardMaterial>>,
+    mut sound_ev: MessageWriter<SoundEvent>,
+) {
+    for PowerUpEvent(power_up, pos) in evr.read() {
+        if !matches!(power_up, PowerUp::Multiball) {
+            continue;
+        }
+        // Extra balls are launched to the top of the table
+        for i in 0..EXTRA_BALLS {
+            let side = i as f32 - (EXTRA_BALLS - 1) as f32 / 2.;
+            let velocity = Vec2::new(-2., side + rand::random_range(-0.3..0.3));
+            let ball_pos = Vec3::new(pos.x, pos.y + side * 0.03, -0.02);
+            let ball_id = ball::spawn(&mut cmds, &mut meshes, &mut mats, ball_pos);
+            cmds.entity(ball_id).insert((
+                ExtraBall(Timer::from_seconds(MULTIBALL_SECS, TimerMode::Once)),
+                LinearVelocity(velocity),
+            ));
+        }
+        sound_ev.write(SoundEvent::BallSpawn);
+    }
+}
+
+// Timed modifier of a ball, a new power-up replaces the old one
+#[derive(Component)]
+struct BallPowerUp {
+    power_up: PowerUp,
+    timer: Timer,
+}
+
+fn on_ball_power_up_system(
+    mut cmds: Commands,
+    mut evr: MessageReader<PowerUpEvent>,
+    mut q_ball: Query<(Entity, &mut Transform, &MeshMaterial3d<StandardMaterial>), With<PinBall>>,
+    mut mats: ResMut<Assets<StandardMaterial>>,
+) {
+    for PowerUpEvent(power_up, _) in evr.read() {
+        if matches!(power_up, PowerUp::Multiball | PowerUp::ExtraBall) {
+            continue;
+        }
+        // All balls on the table get the power-up
+        for (ball_id, mut trans, mat) in q_ball.iter_mut() {
+            trans.scale = match power_up {
+                PowerUp::Enlarged => Vec3::splat(ENLARGED_SCALE),
+                _ => Vec3::ONE,
+            };
+            if let Some(mat) = mats.get_mut(&mat.0) {
+                mat.base_color = power_up.color();
+                mat.emissive = LinearRgba::from(power_up.color()) * 4.;
+            }
+            cmds.entity(ball_id).try_insert(BallPowerUp {
+                power_up: *power_up,
+                timer: Timer::from_seconds(BALL_POWER_UP_SECS, TimerMode::Once),
+            });
+        }
+    }
+}
+
+fn ball_power_up_timeout_system(
+    mut cmds: Commands,
+    mut q_ball: Query<(
+        Entity,
+        &mut BallPowerUp,
+        &mut Transform,
+        &MeshMaterial3d<StandardMaterial>,
+    )>,
+    mut mats: ResMut<Assets<StandardMaterial>>,
+    time: Res<Time>,
+) {
+    for (ball_id, mut ball_pu, mut trans, mat) in q_ball.iter_mut() {
+        if !ball_pu.timer.tick(time.delta()).is_finished() {
+            continue;
+        }
+        log!("🎁 Ball power-up {:?} is over", ball_pu.power_up);
+        trans.scale = Vec3::ONE;
+        if let Some(mat) = mats.get_mut(&mat.0) {
+            mat.base_color = GOLD.into();
+            mat.emissive = BLACK.into();
+        }
+        cmds.entity(ball_id).try_remove::<BallPowerUp>();
+    }
+}
+
+#[derive(Component)]
+struct Burning(Timer);
+
+#[derive(Component)]
+struct BurningLight;
+
+fn on_enemy_hit_system(
+    mut cmds: Commands,
+    mut evr: MessageReader<CollisionWithBallEvent>,
+    mut health_ev: MessageWriter<ChangeHealthEvent>,
+    q_ball_pu: Query<&BallPowerUp, With<PinBall>>,
+    q_enemy: Query<(Entity, &Transform), With<Enemy>>,
+    q_burning: Query<(), With<Burning>>,
+) {
+    for CollisionWithBallEvent(id, ball_id) in evr.read() {
+        let Ok((_, hit_trans)) = q_enemy.get(*id) else {
+            continue;
+        };
+        if let Ok(ball_pu) = q_ball_pu.get(*ball_id) {
+            match ball_pu.power_up {
+                PowerUp::DamageAura => {
+                    let hit_pos = hit_trans.translation;
+                    q_enemy
+                        .iter()
+                        .filter(|(enemy_id, trans)| {
+                            enemy_id != id && trans.translation.distance(hit_pos) < AURA_RADIUS
+                        })
+                        .for_each(|(enemy_id, _)| {
+                            health_ev.write(ChangeHealthEvent::new(enemy_id, AURA_DAMAGE, None));
+                        });
+                }
+                PowerUp::FireBall => {
+                    let burning = Burning(Timer::from_seconds(BURN_SECS, TimerMode::Once));
+                    // Enemy can be despawned in the same frame, the light is only
+                    // spawned together with a successful insert
+                    if q_burning.contains(*id) {
+                        cmds.entity(*id).try_insert(burning);
+                    } else {
+                        cmds.entity(*id).try_insert((
+                            burning,
+                            children![(
+                                Name::new("Burning Light"),
+                                PointLight {
+                                    intensity: 1500.,
+                                    color: ORANGE_RED.into(),
+                                    range: 0.1,
+                                    ..default()
+                                },
+                                Transform::from_xyz(0., 0., 0.03),
+                                BurningLight,
+                            )],
+                        ));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn burning_system(
+    mut cmds: Commands,
+    mut q_burning: Query<(Entity, &mut Burning)>,
+    mut health_ev: MessageWriter<ChangeHealthEvent>,
+    q_light: Query<(Entity, &ChildOf), With<BurningLight>>,
+    time: Res<Time>,
+) {
+    for (enemy_id, mut burning) in q_burning.iter_mut() {
+        health_ev.write(ChangeHealthEvent::new(
+            enemy_id,
+            BURN_DAMAGE * time.delta_secs(),
+            None,
+        ));
+        if burning.0.tick(time.delta()).is_finished() {
+            cmds.entity(enemy_id).try_remove::<Burning>();
+            q_light
+                .iter()
+                .filter(|(_, child_of)| child_of.parent() == enemy_id)
+                .for_each(|(light_id, _)| cmds.entity(light_id).try_despawn());
+        }
+    }
+}
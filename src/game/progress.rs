@@ -26,7 +26,7 @@ impl Plugin for ProgressPlugin {
 pub struct Progress(pub f32);
 
 impl Progress {
-    fn is_full(&self) -> bool {
+    pub fn is_full(&self) -> bool {
         self.0 >= 1.
     }
 }
@@ -1,15 +1,33 @@
-use crate::generated::world_1::road_points::ROAD_POINTS;
+use super::events::collision::GameLayer;
+use crate::generated::world_1::road_points::{ROAD_DISTS, ROAD_POINTS};
 use crate::prelude::*;
-use bevy::color::palettes::css::GREEN;
-use bevy::math::primitives::Sphere;
+use bevy::color::palettes::css::{GREEN, SANDY_BROWN};
+use bevy::math::primitives::{Cuboid, Sphere};
+use rand::seq::IndexedRandom;
+use serde::Deserialize;
+use std::sync::Arc;
+
+pub struct RoadPlugin;
+
+impl Plugin for RoadPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<RoadLayout>()
+            .register_asset_loader(RonAssetLoader::<RoadLayout>::new(&["roads.ron"]));
+    }
+}
 
 #[allow(dead_code)]
 #[derive(Resource)]
 struct RoadAnimations(Vec<Handle<AnimationClip>>);
 
-#[allow(dead_code)]
-#[derive(Resource)]
-struct RoadPath(Vec<Vec3>);
+// Half width of the road, which can be hit by the ball
+const ROAD_SENSOR_RADIUS: f32 = 0.025;
+// Size of the road mesh for segments, which are not part of the world mesh
+const ROAD_WIDTH: f32 = 0.05;
+const ROAD_Z: f32 = 0.002;
+
+#[derive(Component)]
+pub struct RoadSensor;
 
 #[allow(unused_variables)]
 pub fn spawn_road(
@@ -17,13 +35,50 @@ pub fn spawn_road(
     materials: &mut Assets<StandardMaterial>,
     meshes: &mut Assets<Mesh>,
     assets: &PinballDefenseGltfAssets,
+    road: &RoadGraph,
 ) {
     spawner.spawn((
         Name::new("Road Mesh"),
         Mesh3d(assets.road_mesh.clone()),
         MeshMaterial3d(assets.road_material.clone()),
     ));
-    //spawn_road_milestones(parent, materials, meshes);
+    for seg in road.segments.iter().filter(|seg| !seg.is_in_world_mesh) {
+        for w in seg.points.windows(2) {
+            let dir = (w[1] - w[0]).truncate();
+            spawner.spawn((
+                Name::new("Road Mesh"),
+                Mesh3d(meshes.add(Mesh::from(Cuboid::new(dir.length(), ROAD_WIDTH, 0.002)))),
+                MeshMaterial3d(assets.road_material.clone()),
+                Transform::from_translation(w[0].lerp(w[1], 0.5))
+                    .with_rotation(Quat::from_rotation_z(dir.to_angle())),
+            ));
+        }
+    }
+    spawner.spawn((
+        Name::new("Road Sensor"),
+        RoadSensor,
+        Sensor,
+        road_collider(road),
+        DebugRender::collider(SANDY_BROWN.into()),
+        CollisionLayers::new(GameLayer::Map, GameLayer::Ball),
+        Transform::default(),
+    ));
+    //spawn_road_milestones(parent, materials, meshes, road);
+}
+
+// Capsules between all road points
+fn road_collider(road: &RoadGraph) -> Collider {
+    let shapes = road
+        .segments
+        .iter()
+        .flat_map(|seg| seg.points.windows(2))
+        .map(|w| {
+            let capsule =
+                Collider::capsule_endpoints(ROAD_SENSOR_RADIUS, w[0].truncate(), w[1].truncate());
+            (Vec2::ZERO, Rotation::default(), capsule)
+        })
+        .collect();
+    Collider::compound(shapes)
 }
 
 #[allow(dead_code)]
@@ -31,8 +86,9 @@ fn spawn_road_milestones(
     spawner: &mut ChildSpawnerCommands,
     materials: &mut Assets<StandardMaterial>,
     meshes: &mut Assets<Mesh>,
+    road: &RoadGraph,
 ) {
-    for pos in ROAD_POINTS {
+    for pos in road.segments.iter().flat_map(|seg| seg.points.iter()) {
         spawner.spawn((
             Mesh3d(meshes.add(Mesh::from(Sphere {
                 radius: 0.005,
@@ -45,7 +101,278 @@ fn spawn_road_milestones(
                 reflectance: 0.8,
                 ..default()
             })),
-            Transform::from_translation(pos),
+            Transform::from_translation(*pos),
         ));
     }
 }
+
+// Road segments of a world, loaded from assets/data/*.roads.ron
+#[derive(Asset, TypePath, Deserialize)]
+pub struct RoadLayout {
+    segments: Vec<RoadSegmentDef>,
+}
+
+#[derive(Deserialize)]
+struct RoadSegmentDef {
+    path: RoadPath,
+    // Indices of the segments, which follow at the end of this segment
+    #[serde(default)]
+    next: Vec<usize>,
+}
+
+#[derive(Deserialize)]
+enum RoadPath {
+    // First and last index of the road points, which are part of the world mesh
+    Main(usize, usize),
+    // Positions (x, y) on the table
+    Points(Vec<(f32, f32)>),
+}
+
+impl ValidateAsset for RoadLayout {
+    fn validate(&self) -> Result<(), String> {
+        for (i, def) in self.segments.iter().enumerate() {
+            match &def.path {
+                RoadPath::Main(first, last) if first >= last || *last >= ROAD_POINTS.len() => {
+                    return Err(format!("Road segment {i} has invalid road points"));
+                }
+                RoadPath::Points(points) if points.len() < 2 => {
+                    return Err(format!("Road segment {i} needs at least 2 points"));
+                }
+                _ => (),
+            }
+            if let Some(next) = def.next.iter().find(|next| **next >= self.segments.len()) {
+                return Err(format!("Road segment {i} has unknown next segment {next}"));
+            }
+        }
+        if RoadGraph::new(self).portals().is_empty() {
+            return Err("Roads need at least one portal".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl RoadLayout {
+    // The layout is loaded, before the game starts
+    pub fn get<'a>(assets: &PinballDefenseAssets, layouts: &'a Assets<RoadLayout>) -> &'a Self {
+        layouts
+            .get(&assets.roads)
+            .expect("😥 Road layout not loaded")
+    }
+}
+
+// All roads of a world. Segments without predecessor are spawn portals,
+// segments with more than one successor are forks, and segments, which are
+// successor of more than one segment, are merges.
+#[derive(Resource)]
+pub struct RoadGraph {
+    segments: Vec<RoadSegment>,
+}
+
+struct RoadSegment {
+    points: Vec<Vec3>,
+    // Distance from point i to point i + 1
+    dists: Vec<f32>,
+    // Indices of the segments, which follow at the end of this segment
+    next: Vec<usize>,
+    // Road mesh of the segment is part of the world mesh
+    is_in_world_mesh: bool,
+}
+
+impl RoadGraph {
+    pub fn new(layout: &RoadLayout) -> Self {
+        let segments = layout
+            .segments
+            .iter()
+            .map(|def| match &def.path {
+                RoadPath::Main(first, last) => RoadSegment {
+                    points: ROAD_POINTS[*first..=*last].to_vec(),
+                    dists: ROAD_DISTS[*first..*last].to_vec(),
+                    next: def.next.clone(),
+                    is_in_world_mesh: true,
+                },
+                RoadPath::Points(points) => {
+                    let points: Vec<Vec3> = points
+                        .iter()
+                        .map(|(x, y)| Vec3::new(*x, *y, ROAD_Z))
+                        .collect();
+                    RoadSegment {
+                        dists: points.windows(2).map(|w| w[0].distance(w[1])).collect(),
+                        points,
+                        next: def.next.clone(),
+                        is_in_world_mesh: false,
+                    }
+                }
+            })
+            .collect();
+        Self { segments }
+    }
+
+    fn portals(&self) -> Vec<usize> {
+        (0..self.segments.len())
+            .filter(|i| !self.segments.iter().any(|seg| seg.next.contains(i)))
+            .collect()
+    }
+
+    // Walks from the given (or a random) portal to a road end,
+    // at every fork a random way is chosen
+    pub fn route(&self, portal: Option<usize>) -> RoadRoute {
+        let portals = self.portals();
+        let random_portal = || {
+            *portals
+                .choose(&mut rand::rng())
+                .expect("😥 Road graph has no portal")
+        };
+        let first = match portal {
+            Some(i) => portals.get(i).copied().unwrap_or_else(|| {
+                warn!("😥 Road portal {i} doesn't exist, a random portal is used");
+                random_portal()
+            }),
+            None => random_portal(),
+        };
+        let mut way = vec![first];
+        self.walk_forward(&mut way);
+        self.route_along(&way)
+    }
+
+    // Random route from a portal to a road end, which passes the segment nearest to pos
+    pub fn route_near(&self, pos: Vec2) -> RoadRoute {
+        let nearest = (0..self.segments.len())
+            .min_by(|a, b| {
+                let gap = |i: &usize| {
+                    let seg = &self.segments[*i];
+                    nearest_on_line(&seg.points, &seg.dists, pos).1
+                };
+                gap(a).total_cmp(&gap(b))
+            })
+            .expect("😥 Road graph has no segments");
+        let mut way = vec![nearest];
+        // Visited segments are skipped, so a cycle in the graph ends the way
+        loop {
+            let prev: Vec<usize> = (0..self.segments.len())
+                .filter(|i| self.segments[*i].next.contains(&way[0]) && !way.contains(i))
+                .collect();
+            match prev.choose(&mut rand::rng()) {
+                Some(i) => way.insert(0, *i),
+                None => break,
+            }
+        }
+        self.walk_forward(&mut way);
+        self.route_along(&way)
+    }
+
+    // Visited segments are skipped, so a cycle in the graph ends the way
+    fn walk_forward(&self, way: &mut Vec<usize>) {
+        loop {
+            let last = way[way.len() - 1];
+            let next: Vec<usize> = self.segments[last]
+                .next
+                .iter()
+                .copied()
+                .filter(|i| !way.contains(i))
+                .collect();
+            match next.choose(&mut rand::rng()) {
+                Some(i) => way.push(*i),
+                None => break,
+            }
+        }
+    }
+
+    fn route_along(&self, way: &[usize]) -> RoadRoute {
+        let mut points: Vec<Vec3> = Vec::new();
+        let mut dists: Vec<f32> = Vec::new();
+        for seg in way.iter().map(|i| &self.segments[*i]) {
+            match points.last() {
+                // Fork or merge point is already part of the route
+                Some(last) if *last == seg.points[0] => (),
+                Some(last) => {
+                    dists.push(last.distance(seg.points[0]));
+                    points.push(seg.points[0]);
+                }
+                None => points.push(seg.points[0]),
+            }
+            points.extend_from_slice(&seg.points[1..]);
+            dists.extend_from_slice(&seg.dists);
+        }
+        let mut remaining: Vec<f32> = dists
+            .iter()
+            .rev()
+            .scan(0., |sum: &mut f32, dist| {
+                *sum += dist;
+                Some(*sum)
+            })
+            .collect();
+        remaining.reverse();
+        remaining.push(0.);
+        RoadRoute {
+            points: points.into(),
+            dists: dists.into(),
+            remaining: remaining.into(),
+        }
+    }
+}
+
+// Distance along the line to the nearest line position and the gap to it
+fn nearest_on_line(points: &[Vec3], dists: &[f32], pos: Vec2) -> (f32, f32) {
+    let mut line_dist = 0.;
+    let mut nearest = (0., f32::MAX);
+    for (i, seg_len) in dists.iter().enumerate() {
+        let a = points[i].truncate();
+        let b = points[i + 1].truncate();
+        let t = ((pos - a).dot(b - a) / (b - a).length_squared()).clamp(0., 1.);
+        let gap = pos.distance(a.lerp(b, t));
+        if gap < nearest.1 {
+            nearest = (line_dist + t * seg_len, gap);
+        }
+        line_dist += seg_len;
+    }
+    nearest
+}
+// The way along the road, which an enemy follows from portal to road end
+#[derive(Clone)]
+pub struct RoadRoute {
+    points: Arc<[Vec3]>,
+    dists: Arc<[f32]>,
+    // Distance from point i to the road end
+    remaining: Arc<[f32]>,
+}
+
+impl RoadRoute {
+    pub fn start(&self) -> Vec3 {
+        self.points[0]
+    }
+
+    pub fn point(&self, i: usize) -> Vec3 {
+        self.points[i]
+    }
+
+    // Distance from the previous point to point i
+    pub fn dist_to(&self, i: usize) -> f32 {
+        self.dists[i - 1]
+    }
+
+    pub fn remaining_dist_from(&self, i: usize) -> f32 {
+        self.remaining[i]
+    }
+
+    pub fn is_last_point(&self, i: usize) -> bool {
+        i == self.points.len() - 1
+    }
+
+    // Distance from the start to the nearest route position and the gap to it
+    pub fn nearest_dist(&self, pos: Vec2) -> (f32, f32) {
+        nearest_on_line(&self.points, &self.dists, pos)
+    }
+
+    // Position on the route, none before the start or after the end
+    pub fn pos_at(&self, dist: f32) -> Option<Vec3> {
+        let mut route_dist = 0.;
+        for (i, seg_len) in self.dists.iter().enumerate() {
+            if dist >= route_dist && dist <= route_dist + seg_len {
+                let t = (dist - route_dist) / seg_len;
+                return Some(self.points[i].lerp(self.points[i + 1], t));
+            }
+            route_dist += seg_len;
+        }
+        None
+    }
+}
@@ -0,0 +1,155 @@
+use super::audio::SoundEvent;
+use super::ball::{CollisionWithBallEvent, PinBall};
+use super::enemy::Enemy;
+use super::health::ChangeHealthEvent;
+use super::road::{RoadGraph, RoadRoute, RoadSensor};
+use super::world::QueryWorld;
+use super::{EventState, GameState};
+use crate::prelude::*;
+use bevy::color::palettes::css::SANDY_BROWN;
+use bevy::math::primitives::Torus;
+use std::time::Duration;
+
+pub struct ShockwavePlugin;
+
+impl Plugin for ShockwavePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Init), init_resources)
+            .add_systems(
+                Update,
+                (cooldown_system, shockwave_system).run_if(in_state(GameState::Ingame)),
+            )
+            .add_systems(
+                Update,
+                (on_road_hit_system).run_if(in_state(EventState::Active)),
+            );
+    }
+}
+
+// Seconds, until the road can send the next shockwave
+const COOLDOWN_SECS: f32 = 4.;
+// Distance per second along the road
+const SPEED: f32 = 1.2;
+// Distance along the road, until the wave is gone
+const RANGE: f32 = 0.6;
+const HIT_RADIUS: f32 = 0.05;
+const DAMAGE: f32 = -20.;
+
+#[derive(Resource)]
+struct ShockwaveCooldown(Timer);
+
+// Using insert_resource to reset previous resources of same type
+fn init_resources(mut cmds: Commands) {
+    let mut timer = Timer::from_seconds(COOLDOWN_SECS, TimerMode::Once);
+    timer.set_elapsed(Duration::from_secs_f32(COOLDOWN_SECS));
+    cmds.insert_resource(ShockwaveCooldown(timer));
+}
+
+fn cooldown_system(mut cooldown: ResMut<ShockwaveCooldown>, time: Res<Time>) {
+    cooldown.0.tick(time.delta());
+}
+
+// Wave on a route through the hit road, position is the distance from the route start
+#[derive(Component)]
+struct Shockwave {
+    route: RoadRoute,
+    dist: f32,
+    dir: f32,
+    traveled: f32,
+    hit: Vec<Entity>,
+}
+
+fn on_road_hit_system(
+    mut cmds: Commands,
+    mut evr: MessageReader<CollisionWithBallEvent>,
+    mut cooldown: ResMut<ShockwaveCooldown>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut mats: ResMut<Assets<StandardMaterial>>,
+    mut sound_ev: MessageWriter<SoundEvent>,
+    q_road: Query<(), With<RoadSensor>>,
+    q_ball: Query<&Transform, With<PinBall>>,
+    q_pbw: QueryWorld,
+    road: Res<RoadGraph>,
+) {
+    let Some(ball_id) = evr
+        .read()
+        .find(|CollisionWithBallEvent(id, _)| q_road.contains(*id))
+        .map(|CollisionWithBallEvent(_, ball_id)| *ball_id)
+    else {
+        return;
+    };
+    if !cooldown.0.is_finished() {
+        return;
+    }
+    let Ok(ball_trans) = q_ball.get(ball_id) else {
+        return;
+    };
+    let ball_pos = ball_trans.translation.truncate();
+    let route = road.route_near(ball_pos);
+    let (impact_dist, _) = route.nearest_dist(ball_pos);
+    let Ok(world) = q_pbw.single() else {
+        return;
+    };
+    log!("🌊 Shockwave from road distance {impact_dist}");
+    cooldown.0.reset();
+    sound_ev.write(SoundEvent::BallHitsWall);
+
+    let mesh = meshes.add(Mesh::from(Torus::new(0.02, 0.03)));
+    let material = mats.add(StandardMaterial {
+        base_color: SANDY_BROWN.into(),
+        emissive: LinearRgba::from(SANDY_BROWN) * 6.,
+        ..default()
+    });
+    cmds.entity(world).with_children(|p| {
+        for dir in [-1., 1.] {
+            p.spawn((
+                Name::new("Shockwave"),
+                Mesh3d(mesh.clone()),
+                MeshMaterial3d(material.clone()),
+                Transform::from_translation(route.pos_at(impact_dist).unwrap_or_default())
+                    .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+                Shockwave {
+                    route: route.clone(),
+                    dist: impact_dist,
+                    dir,
+                    traveled: 0.,
+                    hit: Vec::new(),
+                },
+            ));
+        }
+    });
+}
+
+fn shockwave_system(
+    mut cmds: Commands,
+    mut q_wave: Query<(Entity, &mut Shockwave, &mut Transform), Without<Enemy>>,
+    mut health_ev: MessageWriter<ChangeHealthEvent>,
+    q_enemy: Query<(Entity, &Transform), With<Enemy>>,
+    time: Res<Time>,
+) {
+    for (wave_id, mut wave, mut trans) in q_wave.iter_mut() {
+        let step = SPEED * time.delta_secs();
+        wave.dist += wave.dir * step;
+        wave.traveled += step;
+        let pos = match wave.route.pos_at(wave.dist) {
+            Some(pos) if wave.traveled < RANGE => pos,
+            // Wave is at the road end or has lost its power
+            _ => {
+                cmds.entity(wave_id).despawn();
+                continue;
+            }
+        };
+        trans.translation = pos;
+        // Ring fades out along the way
+        trans.scale = Vec3::splat(1. - wave.traveled / RANGE);
+
+        for (enemy_id, enemy_trans) in q_enemy.iter() {
+            if !wave.hit.contains(&enemy_id)
+                && enemy_trans.translation.truncate().distance(pos.truncate()) < HIT_RADIUS
+            {
+                health_ev.write(ChangeHealthEvent::new(enemy_id, DAMAGE, None));
+                wave.hit.push(enemy_id);
+            }
+        }
+    }
+}
@@ -0,0 +1,231 @@
+use super::audio::SoundEvent;
+use super::ball::{CollisionWithBallEvent, PinBall};
+use super::ball_starter::{BallSpawn, BallStarterChargeStartedEvent, BallStarterFireEndEvent};
+use super::events::collision::GameLayer;
+use super::level::PointsEvent;
+use super::light::{spawn_lamp, FlashLight};
+use super::world::WorldFrame;
+use super::{EventState, GameState};
+use crate::prelude::*;
+use crate::settings::GraphicsSettings;
+use bevy::color::palettes::css::{DARK_GRAY, HOT_PINK};
+use bevy::math::primitives::Cylinder;
+use rand::seq::IndexedRandom;
+
+pub struct SkillShotPlugin;
+
+impl Plugin for SkillShotPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Init), init_resources)
+            .add_systems(
+                Update,
+                (skill_shot_window_system, skill_shot_lamp_system)
+                    .run_if(in_state(GameState::Ingame)),
+            )
+            .add_systems(
+                Update,
+                (
+                    on_charge_started_system,
+                    on_ball_launched_system,
+                    on_skill_shot_hit_system,
+                )
+                    .run_if(in_state(EventState::Active)),
+            );
+    }
+}
+
+// Seconds after the launch, in which the lit target has to be hit
+const WINDOW_SECS: f32 = 2.5;
+const TARGET_RADIUS: f32 = 0.02;
+// Area in front of the ball spawn, in which a launched ball counts
+const LANE_LENGTH: f32 = 0.3;
+const LANE_HALF_WIDTH: f32 = 0.04;
+
+// Rollover targets near the exit of the ball starter lane
+const TARGET_POSIS: [Vec3; 3] = [
+    Vec3::new(-0.95, 0.55, -0.04),
+    Vec3::new(-0.8, 0.5, -0.04),
+    Vec3::new(-0.65, 0.55, -0.04),
+];
+
+#[derive(Component)]
+struct SkillShotTarget;
+
+#[derive(Component)]
+struct SkillShotLamp(Entity);
+
+// Target, which is lit for the next launch, and the launched ball
+#[derive(Resource)]
+struct SkillShot {
+    lit: Option<Entity>,
+    ball: Option<Entity>,
+    window: Timer,
+    is_active: bool,
+}
+
+impl Default for SkillShot {
+    fn default() -> Self {
+        Self {
+            lit: None,
+            ball: None,
+            window: Timer::from_seconds(WINDOW_SECS, TimerMode::Once),
+            is_active: false,
+        }
+    }
+}
+
+impl SkillShot {
+    fn clear(&mut self) {
+        self.lit = None;
+        self.ball = None;
+        self.is_active = false;
+    }
+}
+
+// Using insert_resource to reset previous resources of same type
+fn init_resources(mut cmds: Commands) {
+    cmds.insert_resource(SkillShot::default());
+}
+
+pub fn spawn_targets(
+    p: &mut ChildSpawnerCommands,
+    mats: &mut Assets<StandardMaterial>,
+    meshes: &mut Assets<Mesh>,
+    assets: &PinballDefenseGltfAssets,
+    g_sett: &GraphicsSettings,
+) {
+    let mesh = meshes.add(Mesh::from(Cylinder::new(TARGET_RADIUS, 0.004)));
+    let material = mats.add(StandardMaterial {
+        base_color: DARK_GRAY.into(),
+        emissive: LinearRgba::from(HOT_PINK) * 0.3,
+        perceptual_roughness: 0.4,
+        metallic: 0.6,
+        ..default()
+    });
+    for pos in TARGET_POSIS {
+        let target_id = p
+            .spawn((
+                Name::new("Skill Shot Target"),
+                SkillShotTarget,
+                Mesh3d(mesh.clone()),
+                MeshMaterial3d(material.clone()),
+                Transform::from_translation(pos)
+                    .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+                Sensor,
+                Collider::circle(TARGET_RADIUS),
+                DebugRender::collider(HOT_PINK.into()),
+                CollisionLayers::new(GameLayer::Map, GameLayer::Ball),
+            ))
+            .id();
+        let lamp_pos = Vec3::new(pos.x, pos.y + 0.04, 0.06);
+        spawn_lamp(
+            p,
+            mats,
+            assets,
+            g_sett,
+            lamp_pos,
+            HOT_PINK.into(),
+            SkillShotLamp(target_id),
+        );
+    }
+}
+
+// Charging the ball starter lights a random target
+fn on_charge_started_system(
+    mut evr: MessageReader<BallStarterChargeStartedEvent>,
+    mut skill_shot: ResMut<SkillShot>,
+    q_target: Query<Entity, With<SkillShotTarget>>,
+) {
+    if evr.read().next().is_none() || skill_shot.is_active {
+        return;
+    }
+    let targets: Vec<Entity> = q_target.iter().collect();
+    skill_shot.lit = targets.choose(&mut rand::rng()).copied();
+}
+
+fn is_in_launch_lane(pos: Vec3, spawn: Vec3) -> bool {
+    (pos.y - spawn.y).abs() < LANE_HALF_WIDTH && pos.x > spawn.x - LANE_LENGTH
+}
+
+// Only a ball, which leaves the ball starter, arms the skill shot
+fn on_ball_launched_system(
+    mut evr: MessageReader<BallStarterFireEndEvent>,
+    mut skill_shot: ResMut<SkillShot>,
+    q_ball: Query<(Entity, &Transform), With<PinBall>>,
+    ball_spawn: Res<BallSpawn>,
+) {
+    if evr.read().next().is_none() || skill_shot.lit.is_none() || skill_shot.is_active {
+        return;
+    }
+    let Some((ball_id, _)) = q_ball
+        .iter()
+        .find(|(_, trans)| is_in_launch_lane(trans.translation, ball_spawn.0))
+    else {
+        log!("🎯 No ball in the launch lane");
+        skill_shot.clear();
+        return;
+    };
+    log!("🎯 Skill shot is active for ball {ball_id:?}");
+    skill_shot.ball = Some(ball_id);
+    skill_shot.is_active = true;
+    skill_shot.window.reset();
+}
+
+fn skill_shot_window_system(mut skill_shot: ResMut<SkillShot>, time: Res<Time>) {
+    if skill_shot.is_active && skill_shot.window.tick(time.delta()).is_finished() {
+        log!("🎯 Skill shot missed");
+        skill_shot.clear();
+    }
+}
+
+// The launched ball has to hit the lit target first, only the walls of the lane are allowed
+fn on_skill_shot_hit_system(
+    mut evr: MessageReader<CollisionWithBallEvent>,
+    mut skill_shot: ResMut<SkillShot>,
+    mut points_ev: MessageWriter<PointsEvent>,
+    mut sound_ev: MessageWriter<SoundEvent>,
+    q_parent: Query<&ChildOf>,
+    q_frame: Query<(), With<WorldFrame>>,
+) {
+    for CollisionWithBallEvent(id, ball_id) in evr.read() {
+        if !skill_shot.is_active || skill_shot.ball != Some(*ball_id) {
+            continue;
+        }
+        if skill_shot.lit == Some(*id) {
+            log!("🎯 Skill shot!");
+            points_ev.write(PointsEvent::SkillShot);
+            sound_ev.write(SoundEvent::PbMenuActive);
+            skill_shot.clear();
+            continue;
+        }
+        let is_frame = q_frame.contains(*id)
+            || q_parent
+                .get(*id)
+                .is_ok_and(|child_of| q_frame.contains(child_of.parent()));
+        if !is_frame {
+            log!("🎯 Skill shot missed, ball hit {id:?} first");
+            skill_shot.clear();
+        }
+    }
+}
+
+// The lit target flashes, until it is hit or missed
+fn skill_shot_lamp_system(
+    mut cmds: Commands,
+    mut q_lamp: Query<(Entity, &SkillShotLamp, &mut Visibility, Has<FlashLight>)>,
+    skill_shot: Res<SkillShot>,
+) {
+    if !skill_shot.is_changed() {
+        return;
+    }
+    for (lamp_id, lamp, mut visi, is_flashing) in q_lamp.iter_mut() {
+        let is_lit = skill_shot.lit == Some(lamp.0);
+        if is_lit && !is_flashing {
+            *visi = Visibility::Inherited;
+            cmds.entity(lamp_id).insert(FlashLight);
+        } else if !is_lit && is_flashing {
+            *visi = Visibility::Hidden;
+            cmds.entity(lamp_id).remove::<FlashLight>();
+        }
+    }
+}
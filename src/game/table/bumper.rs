@@ -0,0 +1,89 @@
+use super::kick_ball;
+use crate::game::audio::SoundEvent;
+use crate::game::ball::{CollisionWithBallEvent, PinBall};
+use crate::game::events::collision::GameLayer;
+use crate::game::level::PointsEvent;
+use crate::game::light::{contact_light_bundle, LightOnCollision};
+use crate::prelude::*;
+use crate::settings::GraphicsSettings;
+use bevy::color::palettes::css::{DEEP_PINK, SILVER};
+use bevy::math::primitives::Cylinder;
+use serde::Deserialize;
+
+const RADIUS: f32 = 0.035;
+// Velocity, which the ball gets away from the bumper
+const KICK_STRENGTH: f32 = 3.;
+
+#[derive(Deserialize)]
+pub(super) struct BumperDef {
+    pos: (f32, f32),
+}
+
+#[derive(Component)]
+pub(super) struct PopBumper;
+
+pub(super) fn spawn_bumper(
+    p: &mut ChildSpawnerCommands,
+    mats: &mut Assets<StandardMaterial>,
+    meshes: &mut Assets<Mesh>,
+    g_sett: &GraphicsSettings,
+    def: &BumperDef,
+) {
+    let pos = Vec3::new(def.pos.0, def.pos.1, -0.04);
+    p.spawn((
+        Name::new("Pop Bumper"),
+        PopBumper,
+        spatial_from_pos(pos),
+        RigidBody::Static,
+        Collider::circle(RADIUS),
+        DebugRender::collider(DEEP_PINK.into()),
+        CollisionLayers::new(GameLayer::Map, GameLayer::Ball),
+        LightOnCollision,
+    ))
+    .with_children(|p| {
+        p.spawn((
+            Name::new("Pop Bumper Body"),
+            Mesh3d(meshes.add(Mesh::from(Cylinder::new(RADIUS, 0.04)))),
+            MeshMaterial3d(mats.add(StandardMaterial {
+                base_color: SILVER.into(),
+                perceptual_roughness: 0.2,
+                metallic: 0.9,
+                ..default()
+            })),
+            Transform::from_xyz(0., 0., 0.02)
+                .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+        ));
+        p.spawn((
+            Name::new("Pop Bumper Cap"),
+            Mesh3d(meshes.add(Mesh::from(Cylinder::new(RADIUS * 1.1, 0.01)))),
+            MeshMaterial3d(mats.add(StandardMaterial {
+                base_color: DEEP_PINK.into(),
+                emissive: LinearRgba::from(DEEP_PINK) * 2.,
+                ..default()
+            })),
+            Transform::from_xyz(0., 0., 0.045)
+                .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+        ));
+        p.spawn(contact_light_bundle(g_sett, DEEP_PINK.into()));
+    });
+}
+
+pub(super) fn on_bumper_hit_system(
+    mut evr: MessageReader<CollisionWithBallEvent>,
+    mut q_ball: Query<(&Transform, &mut LinearVelocity), With<PinBall>>,
+    mut points_ev: MessageWriter<PointsEvent>,
+    mut sound_ev: MessageWriter<SoundEvent>,
+    q_bumper: Query<&Transform, (With<PopBumper>, Without<PinBall>)>,
+) {
+    for CollisionWithBallEvent(id, ball_id) in evr.read() {
+        let Ok(bumper_trans) = q_bumper.get(*id) else {
+            continue;
+        };
+        if let Ok((ball_trans, mut velocity)) = q_ball.get_mut(*ball_id) {
+            let dir = (ball_trans.translation - bumper_trans.translation).truncate();
+            kick_ball(&mut velocity, dir, KICK_STRENGTH);
+        }
+        points_ev.write(PointsEvent::BumperHit);
+        sound_ev.write(SoundEvent::BallHitsWall);
+    }
+}
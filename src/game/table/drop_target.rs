@@ -0,0 +1,190 @@
+use crate::game::audio::SoundEvent;
+use crate::game::ball::CollisionWithBallEvent;
+use crate::game::events::collision::GameLayer;
+use crate::game::level::PointsEvent;
+use crate::game::pinball_menu::PinballMenuTrigger;
+use crate::game::progress::{Progress, ProgressBarCountUpEvent};
+use crate::prelude::*;
+use crate::utils::RelEntity;
+use bevy::color::palettes::css::{GOLD, YELLOW};
+use bevy::math::primitives::Cuboid;
+use serde::Deserialize;
+
+const TARGET_WIDTH: f32 = 0.035;
+const TARGET_GAP: f32 = 0.01;
+// Seconds between the last target going down and the reset of the bank
+const RESET_SECS: f32 = 1.5;
+// Progress for the tower or foundation, when the bank is completed
+const BANK_BONUS: f32 = 0.25;
+
+#[derive(Deserialize)]
+pub(super) struct DropBankDef {
+    pos: (f32, f32),
+    // Degrees, targets are in a row along the rotated y axis
+    angle: f32,
+    count: usize,
+}
+
+#[derive(Component)]
+pub(super) struct DropTargetBank {
+    reset: Option<Timer>,
+}
+
+#[derive(Component)]
+pub(super) struct DropTarget {
+    is_down: bool,
+}
+
+#[derive(Message)]
+pub(super) struct DropBankCompleteEvent;
+
+// Tower or foundation, which the ball hit last and whose progress bar isn't full,
+// gets the bank bonus. A full bar already waits for the pinball menu.
+#[derive(Resource, Default)]
+pub(super) struct BankBonusTarget(Option<Entity>);
+
+type QueryBar<'w, 's, 'a> = Query<'w, 's, (&'a RelEntity, &'a Progress)>;
+
+fn is_bar_full(q_bar: &QueryBar, id: Entity) -> bool {
+    q_bar
+        .iter()
+        .any(|(rel_id, progress)| rel_id.0 == id && progress.is_full())
+}
+
+pub(super) fn spawn_drop_bank(
+    p: &mut ChildSpawnerCommands,
+    mats: &mut Assets<StandardMaterial>,
+    meshes: &mut Assets<Mesh>,
+    def: &DropBankDef,
+) {
+    let mesh = meshes.add(Mesh::from(Cuboid::new(0.012, TARGET_WIDTH, 0.04)));
+    let material = mats.add(StandardMaterial {
+        base_color: YELLOW.into(),
+        emissive: LinearRgba::from(GOLD) * 0.5,
+        perceptual_roughness: 0.5,
+        ..default()
+    });
+    let pos = Vec3::new(def.pos.0, def.pos.1, -0.04);
+    p.spawn((
+        Name::new("Drop Target Bank"),
+        DropTargetBank { reset: None },
+        Transform::from_translation(pos)
+            .with_rotation(Quat::from_rotation_z(def.angle.to_radians())),
+        Visibility::default(),
+    ))
+    .with_children(|p| {
+        let step = TARGET_WIDTH + TARGET_GAP;
+        let first = -step * (def.count as f32 - 1.) / 2.;
+        for i in 0..def.count {
+            p.spawn((
+                Name::new("Drop Target"),
+                DropTarget { is_down: false },
+                Mesh3d(mesh.clone()),
+                MeshMaterial3d(material.clone()),
+                Transform::from_xyz(0., first + step * i as f32, 0.02),
+                RigidBody::Static,
+                target_collider(),
+            ));
+        }
+    });
+}
+
+fn target_collider() -> impl Bundle {
+    (
+        Collider::rectangle(0.012, TARGET_WIDTH),
+        DebugRender::collider(YELLOW.into()),
+        CollisionLayers::new(GameLayer::Map, GameLayer::Ball),
+    )
+}
+
+pub(super) fn on_bonus_target_hit_system(
+    mut evr: MessageReader<CollisionWithBallEvent>,
+    mut bonus_target: ResMut<BankBonusTarget>,
+    q_trigger: Query<(), With<PinballMenuTrigger>>,
+    q_bar: QueryBar,
+) {
+    for CollisionWithBallEvent(id, _) in evr.read() {
+        if q_trigger.contains(*id) && !is_bar_full(&q_bar, *id) {
+            bonus_target.0 = Some(*id);
+        }
+    }
+}
+
+pub(super) fn on_drop_target_hit_system(
+    mut cmds: Commands,
+    mut evr: MessageReader<CollisionWithBallEvent>,
+    mut q_target: Query<(&mut DropTarget, &mut Visibility, &ChildOf)>,
+    mut q_bank: Query<&mut DropTargetBank>,
+    mut points_ev: MessageWriter<PointsEvent>,
+    mut sound_ev: MessageWriter<SoundEvent>,
+    mut prog_bar_ev: MessageWriter<ProgressBarCountUpEvent>,
+    mut bank_complete_ev: MessageWriter<DropBankCompleteEvent>,
+    q_children: Query<&Children>,
+    q_trigger: Query<(), With<PinballMenuTrigger>>,
+    q_bar: QueryBar,
+    bonus_target: Res<BankBonusTarget>,
+) {
+    for CollisionWithBallEvent(id, _) in evr.read() {
+        let bank_id = {
+            let Ok((mut target, mut visi, child_of)) = q_target.get_mut(*id) else {
+                continue;
+            };
+            if target.is_down {
+                continue;
+            }
+            // Target drops into the table
+            target.is_down = true;
+            *visi = Visibility::Hidden;
+            child_of.parent()
+        };
+        cmds.entity(*id).remove::<Collider>();
+        points_ev.write(PointsEvent::DropTargetHit);
+        sound_ev.write(SoundEvent::BallHitsFoundation);
+
+        let is_bank_down = q_children.get(bank_id).is_ok_and(|children| {
+            children
+                .iter()
+                .all(|child_id| q_target.get(child_id).is_ok_and(|(t, _, _)| t.is_down))
+        });
+        if !is_bank_down {
+            continue;
+        }
+        log!("🎯 Drop target bank {bank_id:?} completed");
+        points_ev.write(PointsEvent::DropBankComplete);
+        sound_ev.write(SoundEvent::PbMenuActive);
+        bank_complete_ev.write(DropBankCompleteEvent);
+        if let Some(target_id) = bonus_target
+            .0
+            .filter(|id| q_trigger.contains(*id) && !is_bar_full(&q_bar, *id))
+        {
+            prog_bar_ev.write(ProgressBarCountUpEvent::new(target_id, BANK_BONUS));
+        }
+        if let Ok(mut bank) = q_bank.get_mut(bank_id) {
+            bank.reset = Some(Timer::from_seconds(RESET_SECS, TimerMode::Once));
+        }
+    }
+}
+
+pub(super) fn drop_bank_reset_system(
+    mut cmds: Commands,
+    mut q_bank: Query<(&mut DropTargetBank, &Children)>,
+    mut q_target: Query<(&mut DropTarget, &mut Visibility)>,
+    time: Res<Time>,
+) {
+    for (mut bank, children) in q_bank.iter_mut() {
+        let Some(reset) = bank.reset.as_mut() else {
+            continue;
+        };
+        if !reset.tick(time.delta()).is_finished() {
+            continue;
+        }
+        bank.reset = None;
+        for child_id in children.iter() {
+            if let Ok((mut target, mut visi)) = q_target.get_mut(child_id) {
+                target.is_down = false;
+                *visi = Visibility::Inherited;
+                cmds.entity(child_id).insert(target_collider());
+            }
+        }
+    }
+}
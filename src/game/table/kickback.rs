@@ -0,0 +1,115 @@
+use super::drop_target::DropBankCompleteEvent;
+use crate::assets::PinballDefenseGltfAssets;
+use crate::game::audio::SoundEvent;
+use crate::game::ball::{CollisionWithBallEvent, PinBall};
+use crate::game::events::collision::GameLayer;
+use crate::game::light::{spawn_lamp, LIGHT_INTENSITY};
+use crate::prelude::*;
+use crate::settings::GraphicsSettings;
+use bevy::color::palettes::css::LIME;
+use serde::Deserialize;
+
+// Velocity of the ball, when it gets kicked back
+const KICK_STRENGTH: f32 = 4.5;
+
+#[derive(Deserialize)]
+pub(super) struct KickbackDef {
+    pos: (f32, f32),
+    // Direction, in which the ball is kicked back
+    kick: (f32, f32),
+}
+
+// Kicks the ball out of the outlane once, until it gets lit again
+#[derive(Component)]
+pub(super) struct Kickback {
+    kick_dir: Vec2,
+    is_lit: bool,
+}
+
+// Lamp, which shows that the kickback is lit
+#[derive(Component)]
+pub(super) struct KickbackLamp(Entity);
+
+pub(super) fn spawn_kickback(
+    p: &mut ChildSpawnerCommands,
+    mats: &mut Assets<StandardMaterial>,
+    assets: &PinballDefenseGltfAssets,
+    g_sett: &GraphicsSettings,
+    def: &KickbackDef,
+) {
+    let pos = Vec3::new(def.pos.0, def.pos.1, -0.04);
+    let kickback_id = p
+        .spawn((
+            Name::new("Kickback"),
+            Kickback {
+                kick_dir: Vec2::new(def.kick.0, def.kick.1).normalize_or(Vec2::NEG_X),
+                is_lit: true,
+            },
+            spatial_from_pos(pos),
+            Sensor,
+            Collider::rectangle(0.03, 0.04),
+            DebugRender::collider(LIME.into()),
+            CollisionLayers::new(GameLayer::Map, GameLayer::Ball),
+        ))
+        .id();
+    let lamp_pos = Vec3::new(def.pos.0 - 0.06, def.pos.1, 0.06);
+    spawn_lamp(
+        p,
+        mats,
+        assets,
+        g_sett,
+        lamp_pos,
+        LIME.into(),
+        KickbackLamp(kickback_id),
+    );
+}
+
+pub(super) fn on_kickback_hit_system(
+    mut evr: MessageReader<CollisionWithBallEvent>,
+    mut q_kickback: Query<&mut Kickback>,
+    mut q_ball: Query<&mut LinearVelocity, With<PinBall>>,
+    mut sound_ev: MessageWriter<SoundEvent>,
+) {
+    for CollisionWithBallEvent(id, ball_id) in evr.read() {
+        let Ok(mut kickback) = q_kickback.get_mut(*id) else {
+            continue;
+        };
+        if !kickback.is_lit {
+            continue;
+        }
+        if let Ok(mut velocity) = q_ball.get_mut(*ball_id) {
+            log!("🦵 Kickback fires ball {ball_id:?}");
+            velocity.0 = kickback.kick_dir * KICK_STRENGTH;
+            kickback.is_lit = false;
+            sound_ev.write(SoundEvent::BallStarterFire);
+        }
+    }
+}
+
+// A completed drop target bank lights every kickback again
+pub(super) fn on_drop_bank_complete_system(
+    evr: MessageReader<DropBankCompleteEvent>,
+    mut q_kickback: Query<&mut Kickback>,
+) {
+    if evr.is_empty() {
+        return;
+    }
+    for mut kickback in q_kickback.iter_mut().filter(|k| !k.is_lit) {
+        log!("🦵 Kickback is lit");
+        kickback.is_lit = true;
+    }
+}
+
+pub(super) fn kickback_lamp_system(
+    mut q_lamp: Query<(&KickbackLamp, &mut Visibility, &mut PointLight)>,
+    q_kickback: Query<&Kickback>,
+) {
+    for (lamp, mut visi, mut light) in q_lamp.iter_mut() {
+        let is_lit = q_kickback.get(lamp.0).is_ok_and(|k| k.is_lit);
+        *visi = match is_lit {
+            true => Visibility::Inherited,
+            false => Visibility::Hidden,
+        };
+        light.intensity = if is_lit { LIGHT_INTENSITY } else { 0. };
+    }
+}
@@ -0,0 +1,136 @@
+use self::bumper::{on_bumper_hit_system, spawn_bumper, BumperDef};
+use self::drop_target::{
+    drop_bank_reset_system, on_bonus_target_hit_system, on_drop_target_hit_system, spawn_drop_bank,
+    BankBonusTarget, DropBankCompleteEvent, DropBankDef,
+};
+use self::kickback::{
+    kickback_lamp_system, on_drop_bank_complete_system, on_kickback_hit_system, spawn_kickback,
+    KickbackDef,
+};
+use self::saucer::{on_saucer_hit_system, saucer_kickout_system, spawn_saucer, SaucerDef};
+use self::slingshot::{on_slingshot_hit_system, spawn_slingshot, SlingshotDef};
+use self::spinner::{on_spinner_hit_system, spawn_spinner, spinner_system, SpinnerDef};
+use super::flipper::FlipperConfig;
+use super::{EventState, GameState};
+use crate::assets::PinballDefenseGltfAssets;
+use crate::prelude::*;
+use crate::settings::GraphicsSettings;
+use serde::Deserialize;
+
+mod bumper;
+mod drop_target;
+mod kickback;
+mod saucer;
+mod slingshot;
+mod spinner;
+
+pub struct TablePlugin;
+
+impl Plugin for TablePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<TableLayout>()
+            .register_asset_loader(RonAssetLoader::<TableLayout>::new(&["table.ron"]))
+            .add_message::<DropBankCompleteEvent>()
+            .add_systems(OnEnter(GameState::Init), init_resources)
+            .add_systems(
+                Update,
+                (
+                    drop_bank_reset_system,
+                    spinner_system,
+                    saucer_kickout_system,
+                    kickback_lamp_system,
+                )
+                    .run_if(in_state(GameState::Ingame)),
+            )
+            .add_systems(
+                Update,
+                (
+                    on_bumper_hit_system,
+                    on_slingshot_hit_system,
+                    on_bonus_target_hit_system,
+                    on_drop_target_hit_system,
+                    on_spinner_hit_system,
+                    on_saucer_hit_system,
+                    on_kickback_hit_system,
+                    on_drop_bank_complete_system,
+                )
+                    .run_if(in_state(EventState::Active)),
+            );
+    }
+}
+
+// Active table elements of a world, loaded from assets/data/*.table.ron
+#[derive(Asset, TypePath, Deserialize)]
+pub struct TableLayout {
+    #[serde(default)]
+    bumpers: Vec<BumperDef>,
+    #[serde(default)]
+    slingshots: Vec<SlingshotDef>,
+    #[serde(default)]
+    drop_banks: Vec<DropBankDef>,
+    #[serde(default)]
+    spinners: Vec<SpinnerDef>,
+    #[serde(default)]
+    saucers: Vec<SaucerDef>,
+    #[serde(default)]
+    kickbacks: Vec<KickbackDef>,
+    #[serde(default)]
+    pub left_flipper: FlipperConfig,
+    #[serde(default)]
+    pub right_flipper: FlipperConfig,
+}
+
+impl ValidateAsset for TableLayout {}
+
+// Using insert_resource to reset previous resources of same type
+fn init_resources(mut cmds: Commands) {
+    cmds.insert_resource(BankBonusTarget::default());
+}
+
+impl TableLayout {
+    // The layout is loaded, before the game starts
+    pub fn get<'a>(
+        assets: &PinballDefenseAssets,
+        layouts: &'a Assets<TableLayout>,
+    ) -> &'a TableLayout {
+        layouts
+            .get(&assets.table)
+            .expect("😥 Table layout not loaded")
+    }
+}
+
+pub fn spawn_table_elements(
+    p: &mut ChildSpawnerCommands,
+    mats: &mut Assets<StandardMaterial>,
+    meshes: &mut Assets<Mesh>,
+    assets: &PinballDefenseGltfAssets,
+    g_sett: &GraphicsSettings,
+    layout: &TableLayout,
+) {
+    for def in layout.bumpers.iter() {
+        spawn_bumper(p, mats, meshes, g_sett, def);
+    }
+    for def in layout.slingshots.iter() {
+        spawn_slingshot(p, mats, meshes, g_sett, def);
+    }
+    for def in layout.drop_banks.iter() {
+        spawn_drop_bank(p, mats, meshes, def);
+    }
+    for def in layout.spinners.iter() {
+        spawn_spinner(p, mats, meshes, def);
+    }
+    for def in layout.saucers.iter() {
+        spawn_saucer(p, mats, meshes, def);
+    }
+    for def in layout.kickbacks.iter() {
+        spawn_kickback(p, mats, assets, g_sett, def);
+    }
+}
+
+// Velocity change of the ball, away from the element
+fn kick_ball(velocity: &mut LinearVelocity, dir: Vec2, strength: f32) {
+    let dir = dir.normalize_or_zero();
+    // Remove the part of the velocity, which goes into the element
+    let into = velocity.dot(dir).min(0.);
+    velocity.0 += dir * (strength - into);
+}
@@ -0,0 +1,131 @@
+use crate::game::audio::SoundEvent;
+use crate::game::ball::{CollisionWithBallEvent, PinBall};
+use crate::game::events::collision::GameLayer;
+use crate::game::level::PointsEvent;
+use crate::prelude::*;
+use bevy::color::palettes::css::{DARK_SLATE_GRAY, TURQUOISE};
+use bevy::math::primitives::Torus;
+use serde::Deserialize;
+use std::time::Duration;
+
+const RADIUS: f32 = 0.025;
+// Seconds, the ball is held in the saucer
+const HOLD_SECS: f32 = 1.5;
+// Seconds after a kickout, in which the saucer can not capture
+const COOLDOWN_SECS: f32 = 1.;
+// Velocity of the ball, when it gets kicked out
+const KICK_STRENGTH: f32 = 4.;
+
+#[derive(Deserialize)]
+pub(super) struct SaucerDef {
+    pos: (f32, f32),
+    // Direction of the kickout
+    kick: (f32, f32),
+}
+
+#[derive(Component)]
+pub(super) struct KickoutSaucer {
+    kick_dir: Vec2,
+    held: Option<Entity>,
+    hold: Timer,
+    cooldown: Timer,
+}
+
+pub(super) fn spawn_saucer(
+    p: &mut ChildSpawnerCommands,
+    mats: &mut Assets<StandardMaterial>,
+    meshes: &mut Assets<Mesh>,
+    def: &SaucerDef,
+) {
+    let mut cooldown = Timer::from_seconds(COOLDOWN_SECS, TimerMode::Once);
+    cooldown.set_elapsed(Duration::from_secs_f32(COOLDOWN_SECS));
+    let pos = Vec3::new(def.pos.0, def.pos.1, -0.04);
+    p.spawn((
+        Name::new("Kickout Saucer"),
+        KickoutSaucer {
+            kick_dir: Vec2::new(def.kick.0, def.kick.1).normalize_or(Vec2::NEG_X),
+            held: None,
+            hold: Timer::from_seconds(HOLD_SECS, TimerMode::Once),
+            cooldown,
+        },
+        spatial_from_pos(pos),
+        Sensor,
+        Collider::circle(RADIUS),
+        DebugRender::collider(TURQUOISE.into()),
+        CollisionLayers::new(GameLayer::Map, GameLayer::Ball),
+    ))
+    .with_children(|p| {
+        p.spawn((
+            Name::new("Kickout Saucer Rim"),
+            Mesh3d(meshes.add(Mesh::from(Torus::new(RADIUS * 0.8, RADIUS * 1.2)))),
+            MeshMaterial3d(mats.add(StandardMaterial {
+                base_color: DARK_SLATE_GRAY.into(),
+                emissive: LinearRgba::from(TURQUOISE) * 0.6,
+                perceptual_roughness: 0.3,
+                metallic: 0.8,
+                ..default()
+            })),
+            Transform::from_xyz(0., 0., 0.005)
+                .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+        ));
+    });
+}
+
+pub(super) fn on_saucer_hit_system(
+    mut cmds: Commands,
+    mut evr: MessageReader<CollisionWithBallEvent>,
+    mut q_saucer: Query<(&mut KickoutSaucer, &Transform), Without<PinBall>>,
+    mut q_ball: Query<(&mut Transform, &mut LinearVelocity), With<PinBall>>,
+    mut points_ev: MessageWriter<PointsEvent>,
+) {
+    for CollisionWithBallEvent(id, ball_id) in evr.read() {
+        let Ok((mut saucer, saucer_trans)) = q_saucer.get_mut(*id) else {
+            continue;
+        };
+        if saucer.held.is_some() || !saucer.cooldown.is_finished() {
+            continue;
+        }
+        let Ok((mut ball_trans, mut velocity)) = q_ball.get_mut(*ball_id) else {
+            continue;
+        };
+        log!("🥣 Saucer captures ball {ball_id:?}");
+        ball_trans.translation.x = saucer_trans.translation.x;
+        ball_trans.translation.y = saucer_trans.translation.y;
+        velocity.0 = Vec2::ZERO;
+        // Gravity would pull the ball out of the saucer
+        cmds.entity(*ball_id).insert(GravityScale(0.));
+        saucer.held = Some(*ball_id);
+        saucer.hold.reset();
+        points_ev.write(PointsEvent::SaucerCapture);
+    }
+}
+
+pub(super) fn saucer_kickout_system(
+    mut cmds: Commands,
+    mut q_saucer: Query<&mut KickoutSaucer>,
+    mut q_ball: Query<&mut LinearVelocity, With<PinBall>>,
+    mut sound_ev: MessageWriter<SoundEvent>,
+    time: Res<Time>,
+) {
+    for mut saucer in q_saucer.iter_mut() {
+        saucer.cooldown.tick(time.delta());
+        let Some(ball_id) = saucer.held else {
+            continue;
+        };
+        // Ball is gone, e.g. an extra ball timed out
+        let Ok(mut velocity) = q_ball.get_mut(ball_id) else {
+            saucer.held = None;
+            continue;
+        };
+        if !saucer.hold.tick(time.delta()).is_finished() {
+            velocity.0 = Vec2::ZERO;
+            continue;
+        }
+        log!("🥣 Saucer kicks out ball {ball_id:?}");
+        velocity.0 = saucer.kick_dir * KICK_STRENGTH;
+        cmds.entity(ball_id).remove::<GravityScale>();
+        saucer.held = None;
+        saucer.cooldown.reset();
+        sound_ev.write(SoundEvent::BallStarterFire);
+    }
+}
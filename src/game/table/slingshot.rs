@@ -0,0 +1,84 @@
+use super::kick_ball;
+use crate::game::audio::SoundEvent;
+use crate::game::ball::{CollisionWithBallEvent, PinBall};
+use crate::game::events::collision::GameLayer;
+use crate::game::level::PointsEvent;
+use crate::game::light::{contact_light_bundle, LightOnCollision};
+use crate::prelude::*;
+use crate::settings::GraphicsSettings;
+use bevy::color::palettes::css::{DIM_GRAY, LAWN_GREEN};
+use bevy::math::primitives::Cuboid;
+use serde::Deserialize;
+
+const LENGTH: f32 = 0.14;
+const THICKNESS: f32 = 0.02;
+// Velocity, which the ball gets away from the slingshot
+const KICK_STRENGTH: f32 = 2.5;
+
+#[derive(Deserialize)]
+pub(super) struct SlingshotDef {
+    pos: (f32, f32),
+    // Degrees
+    angle: f32,
+}
+
+#[derive(Component)]
+pub(super) struct Slingshot;
+
+pub(super) fn spawn_slingshot(
+    p: &mut ChildSpawnerCommands,
+    mats: &mut Assets<StandardMaterial>,
+    meshes: &mut Assets<Mesh>,
+    g_sett: &GraphicsSettings,
+    def: &SlingshotDef,
+) {
+    let pos = Vec3::new(def.pos.0, def.pos.1, -0.04);
+    p.spawn((
+        Name::new("Slingshot"),
+        Slingshot,
+        Transform::from_translation(pos)
+            .with_rotation(Quat::from_rotation_z(def.angle.to_radians())),
+        Visibility::default(),
+        RigidBody::Static,
+        Collider::rectangle(LENGTH, THICKNESS),
+        DebugRender::collider(LAWN_GREEN.into()),
+        CollisionLayers::new(GameLayer::Map, GameLayer::Ball),
+        LightOnCollision,
+    ))
+    .with_children(|p| {
+        p.spawn((
+            Name::new("Slingshot Rubber"),
+            Mesh3d(meshes.add(Mesh::from(Cuboid::new(LENGTH, THICKNESS, 0.03)))),
+            MeshMaterial3d(mats.add(StandardMaterial {
+                base_color: DIM_GRAY.into(),
+                perceptual_roughness: 0.9,
+                ..default()
+            })),
+            Transform::from_xyz(0., 0., 0.015),
+        ));
+        p.spawn(contact_light_bundle(g_sett, LAWN_GREEN.into()));
+    });
+}
+
+pub(super) fn on_slingshot_hit_system(
+    mut evr: MessageReader<CollisionWithBallEvent>,
+    mut q_ball: Query<(&Transform, &mut LinearVelocity), With<PinBall>>,
+    mut points_ev: MessageWriter<PointsEvent>,
+    mut sound_ev: MessageWriter<SoundEvent>,
+    q_sling: Query<&Transform, (With<Slingshot>, Without<PinBall>)>,
+) {
+    for CollisionWithBallEvent(id, ball_id) in evr.read() {
+        let Ok(sling_trans) = q_sling.get(*id) else {
+            continue;
+        };
+        if let Ok((ball_trans, mut velocity)) = q_ball.get_mut(*ball_id) {
+            // Kicks along the normal, to the side of the ball
+            let normal = (sling_trans.rotation * Vec3::Y).truncate();
+            let to_ball = (ball_trans.translation - sling_trans.translation).truncate();
+            let dir = normal * normal.dot(to_ball).signum();
+            kick_ball(&mut velocity, dir, KICK_STRENGTH);
+        }
+        points_ev.write(PointsEvent::SlingshotHit);
+        sound_ev.write(SoundEvent::BallHitsWall);
+    }
+}
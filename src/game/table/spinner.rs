@@ -0,0 +1,110 @@
+use crate::game::audio::SoundEvent;
+use crate::game::ball::{CollisionWithBallEvent, PinBall};
+use crate::game::events::collision::GameLayer;
+use crate::game::level::PointsEvent;
+use crate::prelude::*;
+use bevy::color::palettes::css::{LIGHT_STEEL_BLUE, SILVER};
+use bevy::math::primitives::Cuboid;
+use serde::Deserialize;
+use std::f32::consts::TAU;
+
+const WIDTH: f32 = 0.07;
+// Radians per second for every ball speed unit
+const SPIN_PER_SPEED: f32 = 12.;
+// Factor per second, the spin slows down
+const SPIN_DAMPING: f32 = 0.3;
+
+#[derive(Deserialize)]
+pub(super) struct SpinnerDef {
+    pos: (f32, f32),
+    // Degrees, the ball passes along the rotated x axis
+    angle: f32,
+}
+
+#[derive(Component)]
+pub(super) struct Spinner {
+    // Radians per second
+    spin: f32,
+    angle: f32,
+}
+
+#[derive(Component)]
+pub(super) struct SpinnerFlap;
+
+pub(super) fn spawn_spinner(
+    p: &mut ChildSpawnerCommands,
+    mats: &mut Assets<StandardMaterial>,
+    meshes: &mut Assets<Mesh>,
+    def: &SpinnerDef,
+) {
+    let pos = Vec3::new(def.pos.0, def.pos.1, -0.04);
+    p.spawn((
+        Name::new("Spinner"),
+        Spinner {
+            spin: 0.,
+            angle: 0.,
+        },
+        Transform::from_translation(pos)
+            .with_rotation(Quat::from_rotation_z(def.angle.to_radians())),
+        Visibility::default(),
+        Sensor,
+        Collider::rectangle(0.01, WIDTH),
+        DebugRender::collider(SILVER.into()),
+        CollisionLayers::new(GameLayer::Map, GameLayer::Ball),
+    ))
+    .with_children(|p| {
+        p.spawn((
+            Name::new("Spinner Flap"),
+            SpinnerFlap,
+            Mesh3d(meshes.add(Mesh::from(Cuboid::new(0.004, WIDTH, 0.03)))),
+            MeshMaterial3d(mats.add(StandardMaterial {
+                base_color: LIGHT_STEEL_BLUE.into(),
+                perceptual_roughness: 0.2,
+                metallic: 0.9,
+                ..default()
+            })),
+            Transform::from_xyz(0., 0., 0.04),
+        ));
+    });
+}
+
+pub(super) fn on_spinner_hit_system(
+    mut evr: MessageReader<CollisionWithBallEvent>,
+    mut q_spinner: Query<&mut Spinner>,
+    q_ball: Query<&LinearVelocity, With<PinBall>>,
+) {
+    for CollisionWithBallEvent(id, ball_id) in evr.read() {
+        if let Ok(mut spinner) = q_spinner.get_mut(*id) {
+            let speed = q_ball.get(*ball_id).map_or(0., |vel| vel.length());
+            spinner.spin += speed * SPIN_PER_SPEED;
+        }
+    }
+}
+
+pub(super) fn spinner_system(
+    mut q_spinner: Query<(&mut Spinner, &Children)>,
+    mut q_flap: Query<&mut Transform, With<SpinnerFlap>>,
+    mut points_ev: MessageWriter<PointsEvent>,
+    mut sound_ev: MessageWriter<SoundEvent>,
+    time: Res<Time>,
+) {
+    for (mut spinner, children) in q_spinner.iter_mut() {
+        if spinner.spin < 0.1 {
+            continue;
+        }
+        spinner.angle += spinner.spin * time.delta_secs();
+        spinner.spin *= 1. - SPIN_DAMPING * time.delta_secs();
+        // Points for every full revolution
+        while spinner.angle >= TAU {
+            spinner.angle -= TAU;
+            points_ev.write(PointsEvent::SpinnerRevolution);
+            sound_ev.write(SoundEvent::CounterTick);
+        }
+        for child_id in children.iter() {
+            if let Ok(mut trans) = q_flap.get_mut(child_id) {
+                // Flap turns around its long axis
+                trans.rotation = Quat::from_rotation_y(spinner.angle);
+            }
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use super::audio::SoundEvent;
+use super::ball::{OnBallDespawnEvent, PinBall};
+use super::light::FlashLight;
+use super::{EventState, GameState};
+use crate::prelude::*;
+
+pub struct TiltPlugin;
+
+impl Plugin for TiltPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<NudgeEvent>()
+            .add_systems(OnEnter(GameState::Init), init_resources)
+            .add_systems(
+                Update,
+                (tilt_window_system, tilt_lamp_system).run_if(in_state(GameState::Ingame)),
+            )
+            .add_systems(
+                Update,
+                (on_nudge_system, on_ball_despawn_system).run_if(in_state(EventState::Active)),
+            );
+    }
+}
+
+// Nudges in one window, more tilt the table
+const MAX_NUDGES: u32 = 3;
+// Seconds, the nudges are counted after the first one
+const WINDOW_SECS: f32 = 6.;
+// Velocity, which a nudge adds to every ball
+const NUDGE_STRENGTH: f32 = 1.2;
+
+#[derive(Message)]
+pub struct NudgeEvent;
+
+#[derive(Component)]
+pub struct TiltLamp;
+
+#[derive(Resource)]
+pub struct TiltMeter {
+    nudges: u32,
+    window: Timer,
+    is_tilted: bool,
+}
+
+impl Default for TiltMeter {
+    fn default() -> Self {
+        Self {
+            nudges: 0,
+            window: Timer::from_seconds(WINDOW_SECS, TimerMode::Once),
+            is_tilted: false,
+        }
+    }
+}
+
+impl TiltMeter {
+    // Flippers and tower hits do not work, until the ball drains
+    pub fn is_tilted(&self) -> bool {
+        self.is_tilted
+    }
+}
+
+// Using insert_resource to reset previous resources of same type
+fn init_resources(mut cmds: Commands) {
+    cmds.insert_resource(TiltMeter::default());
+}
+
+fn tilt_window_system(mut tilt: ResMut<TiltMeter>, time: Res<Time>) {
+    if tilt.nudges > 0 && tilt.window.tick(time.delta()).is_finished() {
+        tilt.nudges = 0;
+    }
+}
+
+fn on_nudge_system(
+    mut evr: MessageReader<NudgeEvent>,
+    mut tilt: ResMut<TiltMeter>,
+    mut q_ball: Query<&mut LinearVelocity, With<PinBall>>,
+    mut sound_ev: MessageWriter<SoundEvent>,
+) {
+    for _ in evr.read() {
+        if tilt.is_tilted {
+            continue;
+        }
+        if tilt.nudges == 0 {
+            tilt.window.reset();
+        }
+        tilt.nudges += 1;
+        if tilt.nudges > MAX_NUDGES {
+            log!("🚨 Tilt!");
+            tilt.is_tilted = true;
+            sound_ev.write(SoundEvent::EnemyReachEnd);
+            continue;
+        }
+        log!("🫸 Nudge {} of {MAX_NUDGES}", tilt.nudges);
+        // Table gets pushed away from the player
+        let sideways = rand::random_range(-0.5..0.5);
+        for mut velocity in q_ball.iter_mut() {
+            velocity.x -= NUDGE_STRENGTH;
+            velocity.y += sideways;
+        }
+        sound_ev.write(SoundEvent::BallHitsWall);
+    }
+}
+
+fn on_ball_despawn_system(evr: MessageReader<OnBallDespawnEvent>, mut tilt: ResMut<TiltMeter>) {
+    if !evr.is_empty() && tilt.is_tilted {
+        log!("🙂 Tilt is over");
+        *tilt = TiltMeter::default();
+    }
+}
+
+fn tilt_lamp_system(
+    mut cmds: Commands,
+    mut q_lamp: Query<(Entity, &mut Visibility, Has<FlashLight>), With<TiltLamp>>,
+    tilt: Res<TiltMeter>,
+) {
+    if let Ok((lamp_id, mut visi, is_flashing)) = q_lamp.single_mut() {
+        if tilt.is_tilted && !is_flashing {
+            *visi = Visibility::Inherited;
+            cmds.entity(lamp_id).insert(FlashLight);
+        } else if !tilt.is_tilted && is_flashing {
+            *visi = Visibility::Hidden;
+            cmds.entity(lamp_id).remove::<FlashLight>();
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use super::target::TargetPos;
 use super::Tower;
+use crate::game::power::Offline;
 use crate::prelude::*;
 use crate::utils::RelEntity;
 
@@ -18,16 +19,46 @@ pub(super) fn rotate_always_system(
 #[derive(Component)]
 pub(super) struct RotateToTarget;
 
+// Max angle between head and target to shoot
+const ALIGNED_ANGLE: f32 = 0.15;
+
+// Towers with a head, which turns to the target
+#[derive(Component)]
+pub(super) struct Turret {
+    // Radians per second
+    pub rotation_speed: f32,
+    pub is_aligned: bool,
+}
+
+impl Turret {
+    pub fn new(rotation_speed: f32) -> Self {
+        Self {
+            rotation_speed,
+            is_aligned: false,
+        }
+    }
+}
+
 pub(super) fn rotate_to_target_system(
+    time: Res<Time>,
     mut q_rtt: Query<(&mut Transform, &RelEntity), With<RotateToTarget>>,
-    q_spawner: Query<(&Tower, &TargetPos)>,
+    mut q_spawner: Query<(&Tower, &TargetPos, &mut Turret), Without<Offline>>,
 ) {
     for (mut rot_trans, rel_id) in q_rtt.iter_mut() {
-        if let Ok((tower, target_pos)) = q_spawner.get(rel_id.0) {
-            if let Some(target_pos) = target_pos.0 {
-                let direction = target_pos.truncate() - tower.pos.truncate();
-                let angle = direction.angle_to(Vec2::Y);
-                rot_trans.rotation = Quat::from_rotation_z(-angle);
+        if let Ok((tower, target_pos, mut turret)) = q_spawner.get_mut(rel_id.0) {
+            match target_pos.0 {
+                Some(target_pos) => {
+                    let direction = target_pos.truncate() - tower.pos.truncate();
+                    let target_rot = Quat::from_rotation_z(-direction.angle_to(Vec2::Y));
+                    let angle = rot_trans.rotation.angle_between(target_rot);
+                    let max_angle = turret.rotation_speed * time.delta_secs();
+                    rot_trans.rotation = match angle <= max_angle {
+                        true => target_rot,
+                        false => rot_trans.rotation.slerp(target_rot, max_angle / angle),
+                    };
+                    turret.is_aligned = angle - max_angle <= ALIGNED_ANGLE;
+                }
+                None => turret.is_aligned = false,
             }
         }
     }
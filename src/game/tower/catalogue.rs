@@ -0,0 +1,124 @@
+use super::TowerType;
+use crate::game::level::Level;
+use crate::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+// Stats of all tower types, loaded from assets/data/towers.ron
+#[derive(Asset, TypePath, Deserialize)]
+pub struct TowerCatalogue {
+    towers: HashMap<TowerType, TowerStats>,
+}
+
+impl TowerCatalogue {
+    // The catalogue is loaded, before the game starts
+    pub fn get<'a>(
+        assets: &PinballDefenseAssets,
+        catalogues: &'a Assets<TowerCatalogue>,
+    ) -> &'a TowerCatalogue {
+        catalogues
+            .get(&assets.towers)
+            .expect("😥 Tower catalogue not loaded")
+    }
+
+    pub(super) fn stats(&self, tower_type: TowerType) -> &TowerStats {
+        self.towers
+            .get(&tower_type)
+            .unwrap_or_else(|| panic!("😥 No stats for tower type {tower_type:?} found"))
+    }
+
+    // Tower types, which get unlocked on reaching the level
+    pub fn unlocked_on(&self, level: Level) -> impl Iterator<Item = TowerType> + '_ {
+        self.towers
+            .iter()
+            .filter(move |(_, stats)| stats.unlock_level == level)
+            .map(|(tower_type, _)| *tower_type)
+    }
+}
+
+impl ValidateAsset for TowerCatalogue {
+    // Stats with a default of 0 would break the tower types, which use them
+    fn validate(&self) -> Result<(), String> {
+        if let Some(missing) = TowerType::ALL
+            .iter()
+            .find(|tower_type| !self.towers.contains_key(tower_type))
+        {
+            return Err(format!("{missing:?} has no stats"));
+        }
+        for (tower_type, stats) in self.towers.iter() {
+            let required: &[(&str, f32)] = match tower_type {
+                TowerType::Gun | TowerType::Mortar => &[
+                    ("fire_rate", stats.fire_rate),
+                    ("rotation_speed", stats.rotation_speed),
+                ],
+                TowerType::Microwave => &[
+                    ("rotation_speed", stats.rotation_speed),
+                    ("slow_down", stats.slow_down),
+                ],
+                TowerType::Tesla => &[],
+            };
+            if let Some((name, _)) = required.iter().find(|(_, value)| *value <= 0.) {
+                return Err(format!("{tower_type:?} needs {name} greater than 0"));
+            }
+        }
+        Ok(())
+    }
+}
+
+// Only the stats, which are used by the tower type, need to be set
+#[derive(Deserialize)]
+pub(super) struct TowerStats {
+    unlock_level: Level,
+    // Ball hits, until the next upgrade is ready
+    pub upgrade_hits: f32,
+    pub sight_radius: f32,
+    // Needed power from the grid to work
+    pub power_draw: f32,
+    #[serde(default)]
+    pub min_range: f32,
+    // Per second, per projectile or per shell
+    #[serde(default)]
+    pub damage: f32,
+    #[serde(default)]
+    pub splash_radius: f32,
+    // Shots per second
+    #[serde(default)]
+    pub fire_rate: f32,
+    // Radians per second
+    #[serde(default)]
+    pub rotation_speed: f32,
+    // Factor on the enemy speed
+    #[serde(default)]
+    pub slow_down: f32,
+    #[serde(default)]
+    pub chain_count: usize,
+    #[serde(default)]
+    pub upgrade: UpgradeScaling,
+}
+
+// Change of the stats per upgrade tier
+#[derive(Deserialize)]
+#[serde(default)]
+pub(super) struct UpgradeScaling {
+    pub damage_factor: f32,
+    pub range_add: f32,
+    pub rotation_speed_factor: f32,
+    pub damage_radius_add: f32,
+    pub reload_factor: f32,
+    pub slow_down_factor: f32,
+    pub chain_count_add: usize,
+}
+
+impl Default for UpgradeScaling {
+    fn default() -> Self {
+        Self {
+            damage_factor: 1.,
+            range_add: 0.,
+            rotation_speed_factor: 1.,
+            damage_radius_add: 0.,
+            reload_factor: 1.,
+            slow_down_factor: 1.,
+            chain_count_add: 0,
+        }
+    }
+}
@@ -1,5 +1,9 @@
-use super::target::{AimFirstEnemy, EnemiesWithinReach};
+use super::synergy::SynergyBonus;
+use super::target::EnemiesWithinReach;
+use crate::game::base::DamageBoost;
+use crate::game::enemy::Enemy;
 use crate::game::health::ChangeHealthEvent;
+use crate::game::power::Offline;
 use crate::prelude::*;
 
 pub(super) type DamagePerSecond = f32;
@@ -7,35 +11,68 @@ pub(super) type DamagePerSecond = f32;
 #[derive(Component)]
 pub(super) struct DamageOverTime(pub DamagePerSecond);
 
-pub(super) fn afe_damage_over_time_system(
-    time: Res<Time>,
-    q_tower: Query<(Entity, &AimFirstEnemy, &DamageOverTime)>,
-    mut health_ev: MessageWriter<ChangeHealthEvent>,
-) {
-    for (tower_id, target, damage) in q_tower.iter() {
-        if let Some(enemy_id) = target.0 {
-            health_ev.write(ChangeHealthEvent::new(
-                enemy_id,
-                -damage.0 * time.delta_secs(),
-                Some(tower_id),
-            ));
-        }
-    }
-}
+#[derive(Component)]
+pub(super) struct DamageAllTargetsInReach;
 
+// Count of enemies out of reach, which are hit by a jump from a damaged enemy
 #[derive(Component)]
-pub struct DamageAllTargetsInReach;
+pub(super) struct ChainCount(pub usize);
+
+// Distance, over which the damage jumps from one enemy to the next
+const CHAIN_JUMP_RANGE: f32 = 0.1;
+
+// Damage to every enemy within the radius around an impact
+#[derive(Component, Clone, Copy)]
+pub(super) struct SplashDamage {
+    pub damage: f32,
+    pub radius: f32,
+}
+
+type QueryDatirTower<'w, 's, 'a> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'a EnemiesWithinReach,
+        &'a DamageOverTime,
+        &'a SynergyBonus,
+        Option<&'a ChainCount>,
+    ),
+    (With<DamageAllTargetsInReach>, Without<Offline>),
+>;
 
 pub(super) fn datir_damage_over_time_system(
     time: Res<Time>,
-    q_tower: Query<(Entity, &EnemiesWithinReach, &DamageOverTime), With<DamageAllTargetsInReach>>,
+    q_tower: QueryDatirTower,
+    q_enemy: Query<(Entity, &Enemy, &Transform)>,
     mut health_ev: MessageWriter<ChangeHealthEvent>,
+    boost: Res<DamageBoost>,
 ) {
-    for (tower_id, targets, damage) in q_tower.iter() {
-        for enemy_id in targets.0.iter() {
+    for (tower_id, targets, damage, bonus, chain_count) in q_tower.iter() {
+        let hit_posis: Vec<Vec3> = q_enemy
+            .iter_many(targets.0.iter().copied())
+            .map(|(_, _, trans)| trans.translation)
+            .collect();
+        // The nearest enemies out of reach are chained to the damaged ones
+        let mut chain: Vec<(Entity, f32)> = q_enemy
+            .iter()
+            .filter(|(enemy_id, _, _)| !targets.0.contains(enemy_id))
+            .filter_map(|(enemy_id, _, trans)| {
+                let gap = hit_posis
+                    .iter()
+                    .map(|pos| pos.distance(trans.translation))
+                    .min_by(f32::total_cmp)?;
+                (gap < CHAIN_JUMP_RANGE).then_some((enemy_id, gap))
+            })
+            .collect();
+        chain.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+        chain.truncate(chain_count.map_or(0, |count| count.0));
+
+        let chained = chain.into_iter().map(|(enemy_id, _)| enemy_id);
+        for (enemy_id, enemy, _) in q_enemy.iter_many(targets.0.iter().copied().chain(chained)) {
             health_ev.write(ChangeHealthEvent::new(
-                *enemy_id,
-                -damage.0 * time.delta_secs(),
+                enemy_id,
+                -damage.0 * bonus.damage_factor(enemy) * boost.0 * time.delta_secs(),
                 Some(tower_id),
             ));
         }
@@ -18,6 +18,9 @@ use bevy_tweening::{
 };
 use std::{f32::consts::PI, time::Duration};
 
+// Height of the build marks and foundations
+pub(super) const FOUNDATION_Z: f32 = -0.04;
+
 #[derive(Component)]
 pub struct TowerFoundation {
     hit_progress: f32,
@@ -61,20 +64,21 @@ pub(super) fn on_spawn_system(
             if let Ok(world_id) = q_pb_word.single() {
                 cmds.entity(world_id).with_children(|p| {
                     let hit_progress = level.foundation_hit_progress();
-                    spawn(p, &mut mats, &assets, &g_sett, pos, hit_progress);
+                    spawn(p, &mut mats, &assets, &g_sett, pos, hit_progress, 0.);
                 });
             }
         }
     }
 }
 
-fn spawn(
+pub(super) fn spawn(
     spawner: &mut ChildSpawnerCommands,
     mats: &mut Assets<StandardMaterial>,
     assets: &PinballDefenseGltfAssets,
     g_sett: &GraphicsSettings,
     pos: Vec3,
     hit_progress: f32,
+    progress: f32,
 ) {
     let color = Color::srgb_u8(134, 166, 86);
     spawner
@@ -85,7 +89,7 @@ fn spawn(
             p.spawn(lid_top(assets));
             p.spawn(lid_bottom(assets)).with_children(|p| {
                 let bar_trans = Transform::from_translation(Vec3::new(-0.06, 0., 0.));
-                progress::spawn(p, assets, mats, rel_id, bar_trans, color, 0.);
+                progress::spawn(p, assets, mats, rel_id, bar_trans, color, progress);
             });
         });
 }
@@ -217,7 +221,7 @@ pub(super) fn on_progress_system(
     mut sound_ev: MessageWriter<SoundEvent>,
     q_tower_foundation: Query<&TowerFoundation, With<TowerFoundation>>,
 ) {
-    for CollisionWithBallEvent(id) in evr.read() {
+    for CollisionWithBallEvent(id, _) in evr.read() {
         // if *flag == CollisionEventFlags::SENSOR {
         if let Ok(foundation) = q_tower_foundation.get(*id) {
             prog_bar_ev.write(ProgressBarCountUpEvent::new(*id, foundation.hit_progress));
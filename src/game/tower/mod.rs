@@ -1,6 +1,10 @@
-use self::damage::DamageOverTime;
-use self::speed::SlowDownFactor;
-use self::target::{EnemiesWithinReach, SightRadius, TargetPos};
+use self::catalogue::TowerStats;
+use self::damage::{DamageOverTime, SplashDamage};
+use self::projectile::ProjectileDamage;
+use self::recycle::RecycleTowerEvent;
+use self::synergy::SynergyBonus;
+use self::target::{CycleTargetingModeEvent, EnemiesWithinReach, SightRadius, TargetPos};
+use self::upgrade::StatUpgradeEvent;
 use super::audio::SoundEvent;
 use super::ball::CollisionWithBallEvent;
 use super::cfg::CONFIG;
@@ -9,8 +13,10 @@ use super::level::{Level, PointsEvent};
 use super::light::{
     contact_light_bundle, sight_radius_light, FlashLight, LightOnCollision, SightRadiusLight,
 };
-use super::pinball_menu::{PinballMenuTrigger, UpgradeMenuExecuteEvent};
+use super::pinball_menu::{PinballMenuReady, PinballMenuTrigger, UpgradeMenuExecuteEvent};
+use super::power::PowerDraw;
 use super::progress::{self, ProgressBarCountUpEvent};
+use super::tilt::TiltMeter;
 use super::{EventState, GameState};
 use crate::game::analog_counter::AnalogCounterSetEvent;
 use crate::game::light::disable_flash_light;
@@ -21,37 +27,54 @@ use crate::utils::RelEntity;
 use bevy::color::palettes::css::{BEIGE, ORANGE, RED};
 use bevy_tweening::lens::TransformPositionLens;
 use bevy_tweening::{Delay, Sequence, Tween, TweenAnim};
+pub use catalogue::TowerCatalogue;
 use std::time::Duration;
 pub use types::TowerType;
 use types::*;
+pub use upgrade::UpgradeTiers;
 
 mod animations;
+mod catalogue;
 mod damage;
 pub mod foundation;
+mod projectile;
+mod recycle;
 mod speed;
+mod synergy;
 mod target;
 mod types;
+mod upgrade;
 
 pub struct TowerPlugin;
 
 impl Plugin for TowerPlugin {
     fn build(&self, app: &mut App) {
-        app.add_message::<SpawnTowerEvent>()
+        app.init_asset::<TowerCatalogue>()
+            .register_asset_loader(RonAssetLoader::<TowerCatalogue>::new(&["towers.ron"]))
+            .add_message::<SpawnTowerEvent>()
             .add_message::<DamageUpgradeEvent>()
             .add_message::<RangeUpgradeEvent>()
+            .add_message::<CycleTargetingModeEvent>()
+            .add_message::<StatUpgradeEvent>()
+            .add_message::<RecycleTowerEvent>()
             .add_systems(
                 Update,
                 (
                     animations::rotate_always_system,
                     animations::rotate_to_target_system,
-                    damage::afe_damage_over_time_system,
                     damage::datir_damage_over_time_system,
-                    speed::afe_slow_down_system,
-                    target::aim_first_enemy_system,
-                    target::target_pos_by_afe_system,
+                    speed::ae_slow_down_system,
+                    target::aim_enemy_system,
+                    target::target_pos_by_ae_system,
+                    projectile::projectile_flight_system,
+                    types::gun::shoot_system,
                     types::gun::shoot_animation_system,
                     types::microwave::shot_animation_system,
+                    types::mortar::shoot_system,
+                    types::mortar::shell_flight_system,
+                    types::mortar::shot_animation_system,
                     types::tesla::shot_animation_system,
+                    synergy::update_synergy_system,
                 )
                     .run_if(in_state(GameState::Ingame)),
             )
@@ -63,12 +86,15 @@ impl Plugin for TowerPlugin {
                     on_upgrade_system,
                     on_damage_upgrade_system,
                     on_range_upgrade_system,
+                    upgrade::on_stat_upgrade_system,
+                    recycle::on_recycle_system,
                     foundation::on_spawn_system,
                     foundation::on_despawn_system,
                     foundation::on_progress_system,
                     target::on_enemy_enter_reach_system,
                     target::on_enemy_leave_reach_system,
                     target::on_remove_despawned_enemies_from_ewr_system,
+                    target::on_cycle_targeting_mode_system,
                 )
                     .run_if(in_state(EventState::Active)),
             );
@@ -93,31 +119,52 @@ pub struct TowerHead;
 pub enum TowerUpgrade {
     Damage,
     Range,
+    // No real upgrade, switches to the next targeting mode
+    Targeting,
+    RotationSpeed,
+    // Radius of splash damage
+    DamageRadius,
+    FireRate,
+    SlowStrength,
+    // Count of enemies, a tesla damages at once
+    ChainCount,
+    // Removes the tower and brings the foundation back
+    Recycle,
 }
 
-fn tower_bundle(pos: Vec3, sight_radius: f32) -> impl Bundle {
+fn tower_bundle(pos: Vec3, tower_type: TowerType, stats: &TowerStats) -> impl Bundle {
     (
         // General Tower components
         spatial_from_pos(tower_start_pos(pos)),
-        Tower::new(pos),
-        TowerLevel(0),
+        (
+            Tower::new(pos),
+            tower_type,
+            TowerLevel(0),
+            UpgradeTiers::default(),
+            SynergyBonus::default(),
+            PowerDraw(stats.power_draw),
+        ),
         //
         // Enemy target system
-        TargetPos(None),
-        SightRadius(sight_radius),
-        EnemiesWithinReach::default(),
+        (
+            TargetPos(None),
+            SightRadius(stats.sight_radius),
+            EnemiesWithinReach::default(),
+        ),
         //
         // Collider
-        RigidBody::Kinematic,
-        Restitution {
-            coefficient: 2.,
-            combine_rule: CoefficientCombine::Multiply,
-        },
-        DebugRender::collider(RED.into()),
-        Collider::circle(0.06),
-        CollisionLayers::new(GameLayer::Tower, GameLayer::Ball),
-        PinballMenuTrigger::Upgrade,
-        LightOnCollision,
+        (
+            RigidBody::Kinematic,
+            Restitution {
+                coefficient: 2.,
+                combine_rule: CoefficientCombine::Multiply,
+            },
+            DebugRender::collider(RED.into()),
+            Collider::circle(0.06),
+            CollisionLayers::new(GameLayer::Tower, GameLayer::Ball),
+            PinballMenuTrigger::Upgrade,
+            LightOnCollision,
+        ),
         //
         // Spawn animation
         TweenAnim::new(create_tower_spawn_animator(pos)),
@@ -155,12 +202,14 @@ fn spawn(
     assets: &PinballDefenseGltfAssets,
     g_sett: &GraphicsSettings,
     pos: Vec3,
-    sight_radius: f32,
+    tower_type: TowerType,
+    stats: &TowerStats,
     tower_type_bundle: impl Bundle,
     add_to_tower: impl Fn(&mut ChildSpawnerCommands),
 ) {
+    let sight_radius = stats.sight_radius;
     pb_world
-        .spawn(tower_bundle(pos, sight_radius))
+        .spawn(tower_bundle(pos, tower_type, stats))
         .insert(tower_type_bundle)
         .with_children(|p| {
             let tower_id = p.target_entity();
@@ -199,6 +248,19 @@ fn create_tower_spawn_animator(pos: Vec3) -> Sequence {
     delay.then(tween)
 }
 
+// Reverse of the spawn animation
+fn create_tower_despawn_animator(pos: Vec3) -> Tween {
+    Tween::new(
+        EaseFunction::ExponentialInOut,
+        std::time::Duration::from_secs(4),
+        TransformPositionLens {
+            start: pos,
+            end: tower_start_pos(pos),
+        },
+    )
+    .with_cycle_completed_event(true)
+}
+
 fn tower_start_pos(pos: Vec3) -> Vec3 {
     Vec3::new(pos.x, pos.y, pos.z - 0.1)
 }
@@ -210,22 +272,38 @@ fn on_spawn_tower_system(
     mut cmds: Commands,
     mut evr: MessageReader<SpawnTowerEvent>,
     mut mats: ResMut<Assets<StandardMaterial>>,
+    mut meshes: ResMut<Assets<Mesh>>,
     mut points_ev: MessageWriter<PointsEvent>,
     mut sound_ev: MessageWriter<SoundEvent>,
     assets: Res<PinballDefenseGltfAssets>,
+    data: Res<PinballDefenseAssets>,
+    catalogues: Res<Assets<TowerCatalogue>>,
     q_pbw: QueryWorld,
     g_sett: Res<GraphicsSettings>,
 ) {
+    let catalogue = TowerCatalogue::get(&data, &catalogues);
     for ev in evr.read() {
         if let Ok(world) = q_pbw.single() {
             cmds.entity(world).with_children(|spawner| {
                 let pos = ev.1;
+                let stats = catalogue.stats(ev.0);
                 match ev.0 {
-                    TowerType::Gun => gun::spawn(spawner, &mut mats, &assets, &g_sett, pos),
-                    TowerType::Tesla => tesla::spawn(spawner, &mut mats, &assets, &g_sett, pos),
+                    TowerType::Gun => gun::spawn(spawner, &mut mats, &assets, &g_sett, pos, stats),
+                    TowerType::Tesla => {
+                        tesla::spawn(spawner, &mut mats, &assets, &g_sett, pos, stats)
+                    }
                     TowerType::Microwave => {
-                        microwave::spawn(spawner, &mut mats, &assets, &g_sett, pos)
+                        microwave::spawn(spawner, &mut mats, &assets, &g_sett, pos, stats)
                     }
+                    TowerType::Mortar => mortar::spawn(
+                        spawner,
+                        &mut mats,
+                        &mut meshes,
+                        &assets,
+                        &g_sett,
+                        pos,
+                        stats,
+                    ),
                 };
                 points_ev.write(PointsEvent::TowerBuild);
                 sound_ev.write(SoundEvent::TowerBuild);
@@ -239,12 +317,22 @@ fn on_progress_system(
     mut evr: MessageReader<CollisionWithBallEvent>,
     mut points_ev: MessageWriter<PointsEvent>,
     mut sound_ev: MessageWriter<SoundEvent>,
-    q_tower: Query<Entity, With<Tower>>,
+    q_tower: Query<&TowerType, With<Tower>>,
+    data: Res<PinballDefenseAssets>,
+    catalogues: Res<Assets<TowerCatalogue>>,
+    tilt: Res<TiltMeter>,
 ) {
-    evr.read().for_each(|CollisionWithBallEvent(id)| {
+    if tilt.is_tilted() {
+        evr.clear();
+        return;
+    }
+    let catalogue = TowerCatalogue::get(&data, &catalogues);
+    evr.read().for_each(|CollisionWithBallEvent(id, _)| {
         // *flag != CollisionEventFlags::SENSOR &&
-        if q_tower.contains(*id) {
-            prog_bar_ev.write(ProgressBarCountUpEvent::new(*id, CONFIG.tower_hit_progress));
+        if let Ok(tower_type) = q_tower.get(*id) {
+            let progress =
+                CONFIG.tower_hit_progress_factor / catalogue.stats(*tower_type).upgrade_hits;
+            prog_bar_ev.write(ProgressBarCountUpEvent::new(*id, progress));
             points_ev.write(PointsEvent::TowerHit);
             sound_ev.write(SoundEvent::TowerHit);
         }
@@ -259,6 +347,12 @@ impl SoundEvent {
         match upgrade {
             TowerUpgrade::Damage => Self::TowerUpgradeDamage,
             TowerUpgrade::Range => Self::TowerUpgradeRange,
+            TowerUpgrade::Targeting => Self::PbMenuActive,
+            TowerUpgrade::FireRate | TowerUpgrade::DamageRadius | TowerUpgrade::ChainCount => {
+                Self::TowerUpgradeDamage
+            }
+            TowerUpgrade::RotationSpeed | TowerUpgrade::SlowStrength => Self::TowerUpgradeRange,
+            TowerUpgrade::Recycle => Self::TowerBuild,
         }
     }
 }
@@ -269,23 +363,32 @@ fn on_upgrade_system(
     mut q_light: Query<(Entity, &ChildOf, &mut Visibility), With<FlashLight>>,
     mut points_ev: MessageWriter<PointsEvent>,
     mut prog_bar_ev: MessageWriter<ProgressBarCountUpEvent>,
-    mut q_tower: Query<&mut TowerLevel>,
+    mut q_tower: Query<(&mut TowerLevel, &mut UpgradeTiers)>,
     mut ac_set_ev: MessageWriter<AnalogCounterSetEvent>,
     mut range_upgrade_ev: MessageWriter<RangeUpgradeEvent>,
     mut damage_upgrade_ev: MessageWriter<DamageUpgradeEvent>,
+    mut targeting_ev: MessageWriter<CycleTargetingModeEvent>,
+    mut stat_upgrade_ev: MessageWriter<StatUpgradeEvent>,
+    mut recycle_ev: MessageWriter<RecycleTowerEvent>,
     mut sound_ev: MessageWriter<SoundEvent>,
 ) {
     for ev in evr.read() {
-        let mut tower_level = q_tower
-            .get_mut(ev.tower_id)
-            .unwrap_or_else(|_| panic!("😥 No tower level for id {:?} found", ev.tower_id));
-        tower_level.0 += 1;
+        sound_ev.write(SoundEvent::upgrade_sound(ev.upgrade));
+        // Changing the targeting mode and recycling don't use the upgrade progress
+        match ev.upgrade {
+            TowerUpgrade::Targeting => {
+                targeting_ev.write(CycleTargetingModeEvent(ev.tower_id));
+                // The progress bar stays full, so the tower is ready for the menu again
+                cmds.entity(ev.tower_id).try_insert(PinballMenuReady);
+                continue;
+            }
+            TowerUpgrade::Recycle => {
+                recycle_ev.write(RecycleTowerEvent(ev.tower_id));
+                continue;
+            }
+            _ => (),
+        }
         disable_flash_light(&mut cmds, &mut q_light, ev.tower_id);
-        ac_set_ev.write(AnalogCounterSetEvent::new(
-            ev.tower_id,
-            tower_level.0 as u32,
-        ));
-        points_ev.write(PointsEvent::TowerUpgrade);
         prog_bar_ev.write(ProgressBarCountUpEvent::new(ev.tower_id, -1.));
         match ev.upgrade {
             TowerUpgrade::Damage => {
@@ -294,8 +397,20 @@ fn on_upgrade_system(
             TowerUpgrade::Range => {
                 range_upgrade_ev.write(RangeUpgradeEvent(ev.tower_id));
             }
+            upgrade => {
+                stat_upgrade_ev.write(StatUpgradeEvent(ev.tower_id, upgrade));
+            }
         }
-        sound_ev.write(SoundEvent::upgrade_sound(ev.upgrade));
+        let (mut tower_level, mut tiers) = q_tower
+            .get_mut(ev.tower_id)
+            .unwrap_or_else(|_| panic!("😥 No tower level for id {:?} found", ev.tower_id));
+        tower_level.0 += 1;
+        tiers.add(ev.upgrade);
+        ac_set_ev.write(AnalogCounterSetEvent::new(
+            ev.tower_id,
+            tower_level.0 as u32,
+        ));
+        points_ev.write(PointsEvent::TowerUpgrade);
         log!(
             "🐱 Upgrade tower {:?} to level {:?}",
             ev.tower_id,
@@ -320,15 +435,19 @@ type QShotLight<'w, 's, 'a> = Query<
 
 fn on_range_upgrade_system(
     mut evr: MessageReader<RangeUpgradeEvent>,
-    mut q_tower: Query<(Entity, &mut SightRadius), With<Tower>>,
+    mut q_tower: Query<(Entity, &TowerType, &mut SightRadius), With<Tower>>,
     mut q_coll: Query<(&mut Transform, &ChildOf), With<TowerSightSensor>>,
     mut q_sr_light: Query<(&mut SpotLight, &ChildOf), With<SightRadiusLight>>,
     mut q_shot_light: QShotLight,
+    data: Res<PinballDefenseAssets>,
+    catalogues: Res<Assets<TowerCatalogue>>,
 ) {
+    let catalogue = TowerCatalogue::get(&data, &catalogues);
     for ev in evr.read() {
-        if let Ok((tower_id, mut sight_radius)) = q_tower.get_mut(ev.0) {
-            sight_radius.0 += CONFIG.range_upgade_factor;
-            update_collider_size(&mut q_coll, CONFIG.range_upgade_factor, tower_id);
+        if let Ok((tower_id, tower_type, mut sight_radius)) = q_tower.get_mut(ev.0) {
+            let range_add = catalogue.stats(*tower_type).upgrade.range_add;
+            sight_radius.0 += range_add;
+            update_collider_size(&mut q_coll, range_add, tower_id);
             update_sight_radius_light_size(&mut q_sr_light, sight_radius.0, tower_id);
             update_shot_light_size(&mut q_shot_light, sight_radius.0, tower_id);
         }
@@ -377,17 +496,38 @@ fn update_shot_light_size(q_shot_light: &mut QShotLight, sight_radius: f32, towe
 #[derive(Message)]
 struct DamageUpgradeEvent(Entity);
 
+type QueryTowerDamage<'w, 's, 'a> = Query<
+    'w,
+    's,
+    (
+        &'a TowerType,
+        Option<&'a mut DamageOverTime>,
+        Option<&'a mut ProjectileDamage>,
+        Option<&'a mut SplashDamage>,
+    ),
+    With<Tower>,
+>;
+
 fn on_damage_upgrade_system(
     mut evr: MessageReader<DamageUpgradeEvent>,
-    mut q_tower: Query<(Option<&mut DamageOverTime>, Option<&mut SlowDownFactor>), With<Tower>>,
+    mut q_tower: QueryTowerDamage,
+    data: Res<PinballDefenseAssets>,
+    catalogues: Res<Assets<TowerCatalogue>>,
 ) {
+    let catalogue = TowerCatalogue::get(&data, &catalogues);
     for ev in evr.read() {
-        if let Ok((dmg_over_time, slow_down_factor)) = q_tower.get_mut(ev.0) {
+        if let Ok((tower_type, dmg_over_time, projectile_damage, splash_damage)) =
+            q_tower.get_mut(ev.0)
+        {
+            let factor = catalogue.stats(*tower_type).upgrade.damage_factor;
             if let Some(mut dmg_over_time) = dmg_over_time {
-                dmg_over_time.0 *= CONFIG.damage_upgrade_factor;
+                dmg_over_time.0 *= factor;
+            }
+            if let Some(mut projectile_damage) = projectile_damage {
+                projectile_damage.0 *= factor;
             }
-            if let Some(mut slow_down_factor) = slow_down_factor {
-                slow_down_factor.0 *= CONFIG.slow_down_upgrade_factor;
+            if let Some(mut splash_damage) = splash_damage {
+                splash_damage.damage *= factor;
             }
         }
     }
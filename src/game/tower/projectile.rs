@@ -0,0 +1,123 @@
+use super::synergy::SynergyBonus;
+use crate::game::base::DamageBoost;
+use crate::game::enemy::Enemy;
+use crate::game::health::ChangeHealthEvent;
+use crate::prelude::*;
+use bevy::color::palettes::css::GOLD;
+use bevy::math::primitives::Sphere;
+use std::time::Duration;
+
+// Time between two shots of a tower
+#[derive(Component)]
+pub(super) struct Reload(pub Timer);
+
+impl Reload {
+    // Ready to fire at once
+    pub fn new(secs: f32) -> Self {
+        let duration = Duration::from_secs_f32(secs);
+        let mut timer = Timer::new(duration, TimerMode::Once);
+        timer.set_elapsed(duration);
+        Self(timer)
+    }
+
+    pub fn secs_since_shot(&self) -> f32 {
+        self.0.elapsed_secs()
+    }
+}
+
+// Damage of a single projectile
+#[derive(Component)]
+pub(super) struct ProjectileDamage(pub f32);
+
+#[derive(Clone, Copy)]
+pub(super) struct ProjectileSpec {
+    pub speed: f32,
+    pub lifetime: f32,
+    pub hit_radius: f32,
+}
+
+#[derive(Component)]
+pub(super) struct Projectile {
+    tower_id: Entity,
+    velocity: Vec3,
+    lifetime: Timer,
+    hit_radius: f32,
+    damage: f32,
+}
+
+impl Projectile {
+    // Aims ahead of the enemy, so that both meet, if the enemy keeps its way
+    pub fn aimed(
+        tower_id: Entity,
+        start: Vec3,
+        enemy_pos: Vec3,
+        enemy_velocity: Vec3,
+        spec: ProjectileSpec,
+        damage: f32,
+    ) -> Self {
+        let secs = start.truncate().distance(enemy_pos.truncate()) / spec.speed;
+        let target = enemy_pos + enemy_velocity * secs;
+        let direction = (target - start).truncate().normalize_or_zero().extend(0.);
+        Self {
+            tower_id,
+            velocity: direction * spec.speed,
+            lifetime: Timer::from_seconds(spec.lifetime, TimerMode::Once),
+            hit_radius: spec.hit_radius,
+            damage,
+        }
+    }
+}
+
+pub(super) fn projectile(
+    projectile: Projectile,
+    pos: Vec3,
+    meshes: &mut Assets<Mesh>,
+    mats: &mut Assets<StandardMaterial>,
+) -> impl Bundle {
+    (
+        Name::new("Projectile"),
+        Transform::from_translation(pos),
+        Mesh3d(meshes.add(Mesh::from(Sphere { radius: 0.004 }))),
+        MeshMaterial3d(mats.add(StandardMaterial {
+            base_color: GOLD.into(),
+            emissive: LinearRgba::rgb(4., 3., 0.5),
+            ..default()
+        })),
+        projectile,
+    )
+}
+
+pub(super) fn projectile_flight_system(
+    mut cmds: Commands,
+    mut q_projectile: Query<(Entity, &mut Projectile, &mut Transform)>,
+    mut health_ev: MessageWriter<ChangeHealthEvent>,
+    q_enemy: Query<(Entity, &Enemy, &Transform), Without<Projectile>>,
+    q_bonus: Query<&SynergyBonus>,
+    boost: Res<DamageBoost>,
+    time: Res<Time>,
+) {
+    for (projectile_id, mut projectile, mut trans) in q_projectile.iter_mut() {
+        trans.translation += projectile.velocity * time.delta_secs();
+        let pos = trans.translation.truncate();
+        let hit = q_enemy.iter().find(|(_, _, enemy_trans)| {
+            enemy_trans.translation.truncate().distance(pos) <= projectile.hit_radius
+        });
+        if let Some((enemy_id, enemy, _)) = hit {
+            // The tower could be gone in the meantime
+            let factor = q_bonus
+                .get(projectile.tower_id)
+                .map_or(1., |bonus| bonus.damage_factor(enemy));
+            health_ev.write(ChangeHealthEvent::new(
+                enemy_id,
+                -projectile.damage * factor * boost.0,
+                Some(projectile.tower_id),
+            ));
+            cmds.entity(projectile_id).despawn();
+            continue;
+        }
+        // Missed
+        if projectile.lifetime.tick(time.delta()).is_finished() {
+            cmds.entity(projectile_id).despawn();
+        }
+    }
+}
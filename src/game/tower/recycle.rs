@@ -0,0 +1,79 @@
+use super::damage::DamageOverTime;
+use super::foundation::{self, FOUNDATION_Z};
+use super::speed::SlowDownFactor;
+use super::target::AimEnemy;
+use super::{create_tower_despawn_animator, Tower, TowerSightSensor};
+use crate::game::events::tween_completed::AfterTween;
+use crate::game::level::{LevelHub, PointsEvent};
+use crate::game::light::LightOnCollision;
+use crate::game::pinball_menu::PinballMenuTrigger;
+use crate::game::power::PowerDraw;
+use crate::game::world::QueryWorld;
+use crate::prelude::*;
+use crate::settings::GraphicsSettings;
+use bevy_tweening::TweenAnim;
+
+// Progress of the new foundation as refund
+const REFUND_PROGRESS: f32 = 0.5;
+
+#[derive(Message)]
+pub(super) struct RecycleTowerEvent(pub Entity);
+
+pub(super) fn on_recycle_system(
+    mut cmds: Commands,
+    mut evr: MessageReader<RecycleTowerEvent>,
+    mut mats: ResMut<Assets<StandardMaterial>>,
+    mut points_ev: MessageWriter<PointsEvent>,
+    assets: Res<PinballDefenseGltfAssets>,
+    q_tower: Query<&Tower>,
+    q_sight: Query<(Entity, &ChildOf), With<TowerSightSensor>>,
+    q_pbw: QueryWorld,
+    g_sett: Res<GraphicsSettings>,
+    level: Res<LevelHub>,
+) {
+    for ev in evr.read() {
+        let Ok(tower) = q_tower.get(ev.0) else {
+            continue;
+        };
+        let pos = tower.pos;
+        log!("♻️ Recycle tower {:?}", ev.0);
+
+        // Without these components the tower stops working at once
+        cmds.entity(ev.0)
+            .remove::<(
+                Tower,
+                PowerDraw,
+                Collider,
+                PinballMenuTrigger,
+                LightOnCollision,
+                AimEnemy,
+                SlowDownFactor,
+                DamageOverTime,
+            )>()
+            .insert((
+                TweenAnim::new(create_tower_despawn_animator(pos)),
+                AfterTween::DeleteEntity,
+            ));
+
+        q_sight
+            .iter()
+            .filter(|(_, child_of)| child_of.parent() == ev.0)
+            .for_each(|(sight_id, _)| cmds.entity(sight_id).despawn());
+
+        // Foundation to rebuild the spot
+        if let Ok(world) = q_pbw.single() {
+            cmds.entity(world).with_children(|spawner| {
+                foundation::spawn(
+                    spawner,
+                    &mut mats,
+                    &assets,
+                    &g_sett,
+                    Vec3::new(pos.x, pos.y, FOUNDATION_Z),
+                    level.foundation_hit_progress(),
+                    REFUND_PROGRESS,
+                );
+            });
+        }
+        points_ev.write(PointsEvent::TowerRecycle);
+    }
+}
@@ -1,18 +1,20 @@
-use super::target::AimFirstEnemy;
+use super::synergy::SynergyBonus;
+use super::target::AimEnemy;
 use crate::game::enemy::Enemy;
+use crate::game::power::Offline;
 use crate::prelude::*;
 
 #[derive(Component)]
 pub(super) struct SlowDownFactor(pub f32);
 
-pub(super) fn afe_slow_down_system(
+pub(super) fn ae_slow_down_system(
     mut q_enemy: Query<&mut Enemy>,
-    q_tower: Query<(&AimFirstEnemy, &SlowDownFactor)>,
+    q_tower: Query<(&AimEnemy, &SlowDownFactor, &SynergyBonus), Without<Offline>>,
 ) {
-    for (target, slow_factor) in q_tower.iter() {
+    for (target, slow_factor, bonus) in q_tower.iter() {
         if let Some(enemy_id) = target.0 {
             if let Ok(mut enemy) = q_enemy.get_mut(enemy_id) {
-                enemy.slow_down(slow_factor.0);
+                enemy.slow_down(slow_factor.0 * bonus.slow_down);
             }
         }
     }
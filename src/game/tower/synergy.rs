@@ -0,0 +1,131 @@
+use super::{Tower, TowerLevel, TowerType};
+use crate::game::enemy::Enemy;
+use crate::game::world::QueryWorld;
+use crate::prelude::*;
+use bevy::color::palettes::css::AQUA;
+use bevy::math::primitives::Cylinder;
+use bevy::platform::collections::HashMap;
+
+// Towers within this distance are neighbours
+const LINK_RADIUS: f32 = 0.32;
+
+// Stat modifiers from neighbouring towers
+#[derive(Component, Clone, Copy)]
+pub(super) struct SynergyBonus {
+    // Damage factor against slowed enemies
+    pub slowed_damage: f32,
+    // Factor on the speed of slowed enemies
+    pub slow_down: f32,
+}
+
+impl Default for SynergyBonus {
+    fn default() -> Self {
+        Self {
+            slowed_damage: 1.,
+            slow_down: 1.,
+        }
+    }
+}
+
+impl SynergyBonus {
+    // Returns true, if the neighbour gives a bonus
+    fn add_neighbour(&mut self, tower: TowerType, neighbour: TowerType) -> bool {
+        use TowerType::*;
+        match (tower, neighbour) {
+            // Slowed enemies are easy targets
+            (Gun | Tesla | Mortar, Microwave) => {
+                self.slowed_damage += 0.5;
+                true
+            }
+            // Tesla charge makes the microwave stronger
+            (Microwave, Tesla) => {
+                self.slow_down *= 0.8;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn damage_factor(&self, enemy: &Enemy) -> f32 {
+        match enemy.is_slowed() {
+            true => self.slowed_damage,
+            false => 1.,
+        }
+    }
+}
+
+#[derive(Component)]
+pub(super) struct SynergyLink;
+
+pub(super) fn update_synergy_system(
+    mut cmds: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut mats: ResMut<Assets<StandardMaterial>>,
+    mut q_tower: Query<(Entity, &Tower, &TowerType, &mut SynergyBonus)>,
+    mut removed_towers: RemovedComponents<Tower>,
+    q_changed: Query<(), Changed<TowerLevel>>,
+    q_link: Query<Entity, With<SynergyLink>>,
+    q_pbw: QueryWorld,
+) {
+    // Only after a tower was built, upgraded or removed
+    let is_removed = removed_towers.read().count() > 0;
+    if q_changed.is_empty() && !is_removed {
+        return;
+    }
+    let towers: Vec<(Entity, Vec3, TowerType)> = q_tower
+        .iter()
+        .map(|(id, tower, tower_type, _)| (id, tower.pos, *tower_type))
+        .collect();
+    let mut bonuses: HashMap<Entity, SynergyBonus> = HashMap::default();
+    let mut links: Vec<(Vec3, Vec3)> = Vec::new();
+    for (i, (id_a, pos_a, type_a)) in towers.iter().enumerate() {
+        for (id_b, pos_b, type_b) in towers.iter().skip(i + 1) {
+            if pos_a.distance(*pos_b) > LINK_RADIUS {
+                continue;
+            }
+            let bonus_a = bonuses.entry(*id_a).or_default();
+            let is_linked_a = bonus_a.add_neighbour(*type_a, *type_b);
+            let bonus_b = bonuses.entry(*id_b).or_default();
+            let is_linked_b = bonus_b.add_neighbour(*type_b, *type_a);
+            if is_linked_a || is_linked_b {
+                links.push((*pos_a, *pos_b));
+            }
+        }
+    }
+    for (tower_id, _, _, mut bonus) in q_tower.iter_mut() {
+        *bonus = bonuses.get(&tower_id).copied().unwrap_or_default();
+    }
+
+    // Visual links
+    q_link
+        .iter()
+        .for_each(|link_id| cmds.entity(link_id).despawn());
+    if let Ok(world) = q_pbw.single() {
+        cmds.entity(world).with_children(|spawner| {
+            for (from, to) in links {
+                spawner.spawn(link(from, to, &mut meshes, &mut mats));
+            }
+        });
+    }
+}
+
+fn link(
+    from: Vec3,
+    to: Vec3,
+    meshes: &mut Assets<Mesh>,
+    mats: &mut Assets<StandardMaterial>,
+) -> impl Bundle {
+    let direction = to - from;
+    (
+        Name::new("Synergy Link"),
+        SynergyLink,
+        Mesh3d(meshes.add(Mesh::from(Cylinder::new(0.002, direction.length())))),
+        MeshMaterial3d(mats.add(StandardMaterial {
+            base_color: AQUA.into(),
+            emissive: LinearRgba::rgb(0., 2., 2.),
+            ..default()
+        })),
+        Transform::from_translation((from + to) / 2. + Vec3::Z * 0.03)
+            .with_rotation(Quat::from_rotation_arc(Vec3::Y, direction.normalize())),
+    )
+}
@@ -1,5 +1,6 @@
-use super::TowerSightSensor;
+use super::{Tower, TowerSightSensor};
 use crate::game::enemy::{Enemy, OnEnemyDespawnEvent};
+use crate::game::health::Health;
 use crate::prelude::*;
 use bevy::platform::collections::HashSet;
 
@@ -9,29 +10,101 @@ pub(super) struct SightRadius(pub f32);
 #[derive(Component)]
 pub(super) struct TargetPos(pub Option<Vec3>);
 
-pub(super) fn target_pos_by_afe_system(
-    mut q_afe: Query<(&mut TargetPos, &AimFirstEnemy)>,
+pub(super) fn target_pos_by_ae_system(
+    mut q_ae: Query<(&mut TargetPos, &AimEnemy)>,
     q_enemy: Query<&Transform, With<Enemy>>,
 ) {
-    for (mut target_pos, afe) in q_afe.iter_mut() {
-        target_pos.0 = afe
-            .0
-            .and_then(|enemy_id| q_enemy.get(enemy_id).ok().map(|item| item.translation));
+    for (mut target_pos, ae) in q_ae.iter_mut() {
+        target_pos.0 =
+            ae.0.and_then(|enemy_id| q_enemy.get(enemy_id).ok().map(|item| item.translation));
     }
 }
 
 #[derive(Component)]
-pub(super) struct AimFirstEnemy(pub Option<Entity>);
-
-pub(super) fn aim_first_enemy_system(mut q_afe: Query<(&mut AimFirstEnemy, &EnemiesWithinReach)>) {
-    for (mut aim_enemy, ewr) in q_afe.iter_mut() {
-        match aim_enemy.0 {
-            Some(enemy_id) => {
-                if !ewr.0.contains(&enemy_id) {
-                    aim_enemy.0 = None;
-                }
-            }
-            None => aim_enemy.0 = ewr.0.iter().next().copied(),
+pub(super) struct AimEnemy(pub Option<Entity>);
+
+// Enemies closer to the tower can't be aimed
+#[derive(Component)]
+pub(super) struct MinRange(pub f32);
+
+// Which enemy within reach a tower aims at
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(super) enum TargetingMode {
+    // Nearest to the road end
+    #[default]
+    First,
+    // Farthest from the road end
+    Last,
+    Strongest,
+    Weakest,
+    // Nearest to the tower
+    Closest,
+}
+
+impl TargetingMode {
+    fn next(&self) -> Self {
+        use TargetingMode::*;
+        match self {
+            First => Last,
+            Last => Strongest,
+            Strongest => Weakest,
+            Weakest => Closest,
+            Closest => First,
+        }
+    }
+
+    // The lower the rating, the better the target
+    fn rating(&self, enemy: &Enemy, health: &Health, distance_to_tower: f32) -> f32 {
+        use TargetingMode::*;
+        match self {
+            First => enemy.remaining_distance(),
+            Last => -enemy.remaining_distance(),
+            Strongest => -health.current(),
+            Weakest => health.current(),
+            Closest => distance_to_tower,
+        }
+    }
+}
+
+pub(super) fn aim_enemy_system(
+    mut q_ae: Query<(
+        &mut AimEnemy,
+        &TargetingMode,
+        &EnemiesWithinReach,
+        &Tower,
+        Option<&MinRange>,
+    )>,
+    q_enemy: Query<(&Enemy, &Health, &Transform)>,
+) {
+    for (mut aim_enemy, mode, ewr, tower, min_range) in q_ae.iter_mut() {
+        let min_range = min_range.map_or(0., |min_range| min_range.0);
+        let target = ewr
+            .0
+            .iter()
+            .filter_map(|enemy_id| {
+                let (enemy, health, trans) = q_enemy.get(*enemy_id).ok()?;
+                let distance = trans.translation.distance(tower.pos);
+                (distance >= min_range).then(|| (*enemy_id, mode.rating(enemy, health, distance)))
+            })
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(enemy_id, _)| enemy_id);
+        if aim_enemy.0 != target {
+            aim_enemy.0 = target;
+        }
+    }
+}
+
+#[derive(Message)]
+pub(super) struct CycleTargetingModeEvent(pub Entity);
+
+pub(super) fn on_cycle_targeting_mode_system(
+    mut evr: MessageReader<CycleTargetingModeEvent>,
+    mut q_mode: Query<&mut TargetingMode>,
+) {
+    for ev in evr.read() {
+        if let Ok(mut mode) = q_mode.get_mut(ev.0) {
+            *mode = mode.next();
+            log!("🎯 Tower {:?} targets {:?} enemy now", ev.0, *mode);
         }
     }
 }
@@ -1,7 +1,12 @@
-use super::animations::RotateToTarget;
-use super::target::AimFirstEnemy;
-use crate::game::tower::damage::DamageOverTime;
-use crate::game::tower::{tower_material, ShotLight, TowerHead};
+use super::animations::{RotateToTarget, Turret};
+use super::catalogue::TowerStats;
+use super::projectile::{projectile, Projectile, ProjectileDamage, ProjectileSpec, Reload};
+use super::target::{AimEnemy, TargetingMode};
+use super::TowerType;
+use crate::game::enemy::Enemy;
+use crate::game::power::Offline;
+use crate::game::tower::{tower_material, ShotLight, Tower, TowerHead};
+use crate::game::world::QueryWorld;
 use crate::prelude::*;
 use crate::settings::GraphicsSettings;
 use crate::utils::RelEntity;
@@ -9,6 +14,13 @@ use crate::utils::RelEntity;
 #[derive(Component)]
 pub struct GunTower;
 
+const BULLET: ProjectileSpec = ProjectileSpec {
+    speed: 2.,
+    lifetime: 0.4,
+    hit_radius: 0.03,
+};
+const SHOT_FLASH_SECS: f32 = 0.05;
+
 #[derive(Component)]
 pub struct GunTowerMount;
 
@@ -24,8 +36,9 @@ pub fn spawn(
     assets: &PinballDefenseGltfAssets,
     g_sett: &GraphicsSettings,
     pos: Vec3,
+    stats: &TowerStats,
 ) {
-    let sight_radius = 0.3;
+    let sight_radius = stats.sight_radius;
     let tower_mat = mats.add(tower_material());
 
     // Tower
@@ -35,12 +48,16 @@ pub fn spawn(
         assets,
         g_sett,
         pos,
-        sight_radius,
+        TowerType::Gun,
+        stats,
         (
             Name::new(" Gun Tower"),
             GunTower,
-            AimFirstEnemy(None),
-            DamageOverTime(100.),
+            AimEnemy(None),
+            TargetingMode::default(),
+            Turret::new(stats.rotation_speed),
+            ProjectileDamage(stats.damage),
+            Reload::new(1. / stats.fire_rate),
         ),
         |tower| {
             let rel_id = tower.target_entity();
@@ -145,30 +162,76 @@ fn mounting(
     )
 }
 
-pub(in super::super) fn shoot_animation_system(
+type QueryGunTower<'w, 's, 'a> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'a Tower,
+        &'a AimEnemy,
+        &'a Turret,
+        &'a ProjectileDamage,
+        &'a mut Reload,
+    ),
+    (With<GunTower>, Without<Offline>),
+>;
+
+pub(in super::super) fn shoot_system(
+    mut cmds: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut mats: ResMut<Assets<StandardMaterial>>,
+    mut q_gun_tower: QueryGunTower,
+    q_enemy: Query<(&Enemy, &Transform)>,
+    q_pbw: QueryWorld,
     time: Res<Time>,
-    q_gun_tower: Query<(Entity, &AimFirstEnemy), With<GunTower>>,
+) {
+    let Ok(world) = q_pbw.single() else {
+        return;
+    };
+    for (tower_id, tower, aim_enemy, turret, damage, mut reload) in q_gun_tower.iter_mut() {
+        reload.0.tick(time.delta());
+        if !reload.0.is_finished() || !turret.is_aligned {
+            continue;
+        }
+        let Some((enemy, enemy_trans)) = aim_enemy.0.and_then(|id| q_enemy.get(id).ok()) else {
+            continue;
+        };
+        reload.0.reset();
+        let start = tower.pos + Vec3::Z * 0.04;
+        let bullet = Projectile::aimed(
+            tower_id,
+            start,
+            enemy_trans.translation,
+            enemy.velocity(),
+            BULLET,
+            damage.0,
+        );
+        cmds.entity(world).with_children(|spawner| {
+            spawner.spawn(projectile(bullet, start, &mut meshes, &mut mats));
+        });
+    }
+}
+
+pub(in super::super) fn shoot_animation_system(
+    q_gun_tower: Query<(Entity, &Reload), With<GunTower>>,
     mut q_barrel: Query<(&mut Transform, &RelEntity), With<GunTowerBarrel>>,
     mut q_muzzle_flash: Query<
         (&mut Visibility, &mut SpotLight, &RelEntity),
         With<MuzzleFlashLight>,
     >,
 ) {
-    for (tower_id, enemy_id) in q_gun_tower.iter() {
+    for (tower_id, reload) in q_gun_tower.iter() {
         let mut flash = get_flash(&mut q_muzzle_flash, tower_id);
-        match enemy_id.0 {
-            Some(_) => {
-                let sin = (time.elapsed_secs() * 64.).sin();
-                *flash.0 = Visibility::Inherited;
-                get_barrel(&mut q_barrel, tower_id).0.translation.y = sin * 0.002;
-                flash.1.intensity = (sin + 1.) * 32.;
-            }
-            None => {
-                if *flash.0 != Visibility::Hidden {
-                    *flash.0 = Visibility::Hidden;
-                    get_barrel(&mut q_barrel, tower_id).0.translation.y = 0.;
-                }
-            }
+        let since_shot = reload.secs_since_shot();
+        if since_shot < SHOT_FLASH_SECS {
+            // Recoil
+            let strength = 1. - since_shot / SHOT_FLASH_SECS;
+            *flash.0 = Visibility::Inherited;
+            get_barrel(&mut q_barrel, tower_id).0.translation.y = -strength * 0.003;
+            flash.1.intensity = strength * 64.;
+        } else if *flash.0 != Visibility::Hidden {
+            *flash.0 = Visibility::Hidden;
+            get_barrel(&mut q_barrel, tower_id).0.translation.y = 0.;
         }
     }
 }
@@ -1,7 +1,9 @@
-use super::{tower_material, TowerHead};
-use crate::game::tower::animations::RotateToTarget;
+use super::catalogue::TowerStats;
+use super::{tower_material, TowerHead, TowerType};
+use crate::game::power::Offline;
+use crate::game::tower::animations::{RotateToTarget, Turret};
 use crate::game::tower::speed::SlowDownFactor;
-use crate::game::tower::target::AimFirstEnemy;
+use crate::game::tower::target::{AimEnemy, TargetingMode};
 use crate::game::tower::ShotLight;
 use crate::prelude::*;
 use crate::settings::GraphicsSettings;
@@ -17,8 +19,9 @@ pub fn spawn(
     assets: &PinballDefenseGltfAssets,
     g_sett: &GraphicsSettings,
     pos: Vec3,
+    stats: &TowerStats,
 ) {
-    let sight_radius = 0.3;
+    let sight_radius = stats.sight_radius;
     let tower_mat = mats.add(tower_material());
     super::spawn(
         pb_world,
@@ -26,12 +29,15 @@ pub fn spawn(
         assets,
         g_sett,
         pos,
-        sight_radius,
+        TowerType::Microwave,
+        stats,
         (
             Name::new("Microwave Tower"),
             MicrowaveTower,
-            AimFirstEnemy(None),
-            SlowDownFactor(0.5),
+            AimEnemy(None),
+            TargetingMode::default(),
+            Turret::new(stats.rotation_speed),
+            SlowDownFactor(stats.slow_down),
         ),
         |tower| {
             let rel_id = tower.target_entity();
@@ -84,15 +90,15 @@ fn slow_down_flash_light(g_sett: &GraphicsSettings, rel_id: Entity, range: f32)
 
 pub(in super::super) fn shot_animation_system(
     time: Res<Time>,
-    q_gun_tower: Query<(Entity, &AimFirstEnemy), With<MicrowaveTower>>,
+    q_gun_tower: Query<(Entity, &AimEnemy, Has<Offline>), With<MicrowaveTower>>,
     mut q_slow_flash: Query<
         (&mut Visibility, &mut SpotLight, &RelEntity),
         With<SlowDownFlashLight>,
     >,
 ) {
-    for (tower_id, enemy_id) in q_gun_tower.iter() {
+    for (tower_id, enemy_id, is_offline) in q_gun_tower.iter() {
         let mut flash = get_flash(&mut q_slow_flash, tower_id);
-        match enemy_id.0 {
+        match enemy_id.0.filter(|_| !is_offline) {
             Some(_) => {
                 let sin = (time.elapsed_secs() * 16.).sin();
                 *flash.0 = Visibility::Inherited;
@@ -1,11 +1,23 @@
 use super::*;
+use serde::Deserialize;
 pub(super) mod gun;
 pub(super) mod microwave;
+pub(super) mod mortar;
 pub(super) mod tesla;
 
-#[derive(Component, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[derive(Component, Deserialize, Clone, Copy, Debug, Hash, PartialEq, Eq)]
 pub enum TowerType {
     Gun,
     Tesla,
     Microwave,
+    Mortar,
+}
+
+impl TowerType {
+    pub(super) const ALL: [TowerType; 4] = [
+        TowerType::Gun,
+        TowerType::Tesla,
+        TowerType::Microwave,
+        TowerType::Mortar,
+    ];
 }
@@ -0,0 +1,265 @@
+use super::animations::{RotateToTarget, Turret};
+use super::catalogue::TowerStats;
+use super::damage::SplashDamage;
+use super::projectile::Reload;
+use super::synergy::SynergyBonus;
+use super::target::{AimEnemy, MinRange, TargetingMode};
+use super::{tower_material, ShotLight, Tower, TowerHead, TowerType};
+use crate::game::base::DamageBoost;
+use crate::game::enemy::Enemy;
+use crate::game::health::ChangeHealthEvent;
+use crate::game::power::Offline;
+use crate::game::world::QueryWorld;
+use crate::prelude::*;
+use crate::settings::GraphicsSettings;
+use crate::utils::RelEntity;
+use bevy::color::palettes::css::{DARK_SLATE_GRAY, ORANGE};
+use bevy::math::primitives::{Cylinder, Sphere};
+
+#[derive(Component)]
+pub struct MortarTower;
+
+pub fn spawn(
+    pb_world: &mut ChildSpawnerCommands,
+    mats: &mut Assets<StandardMaterial>,
+    meshes: &mut Assets<Mesh>,
+    assets: &PinballDefenseGltfAssets,
+    g_sett: &GraphicsSettings,
+    pos: Vec3,
+    stats: &TowerStats,
+) {
+    let sight_radius = stats.sight_radius;
+    let tower_mat = mats.add(tower_material());
+    let barrel_mesh = meshes.add(Mesh::from(Cylinder::new(0.012, 0.045)));
+    super::spawn(
+        pb_world,
+        mats,
+        assets,
+        g_sett,
+        pos,
+        TowerType::Mortar,
+        stats,
+        (
+            Name::new("Mortar Tower"),
+            MortarTower,
+            AimEnemy(None),
+            TargetingMode::default(),
+            Turret::new(stats.rotation_speed),
+            MinRange(stats.min_range),
+            SplashDamage {
+                damage: stats.damage,
+                radius: stats.splash_radius,
+            },
+            Reload::new(1. / stats.fire_rate),
+        ),
+        |tower| {
+            let rel_id = tower.target_entity();
+            tower
+                .spawn(head(tower_mat.clone(), assets, rel_id))
+                .with_children(|head| {
+                    head.spawn(barrel(tower_mat.clone(), barrel_mesh.clone()));
+                });
+            tower.spawn(shot_flash_light(g_sett, rel_id, sight_radius));
+        },
+    );
+}
+
+fn head(
+    material: Handle<StandardMaterial>,
+    assets: &PinballDefenseGltfAssets,
+    rel_id: Entity,
+) -> impl Bundle {
+    (
+        Name::new("Head"),
+        TowerHead,
+        Mesh3d(assets.tower_mg_mounting.clone()),
+        MeshMaterial3d(material),
+        Transform {
+            translation: Vec3::new(0., 0., 0.023),
+            scale: Vec3::new(0.9, 0.9, 0.9),
+            ..default()
+        },
+        RotateToTarget,
+        RelEntity(rel_id),
+    )
+}
+
+// Tilted upwards in the direction of the target
+fn barrel(material: Handle<StandardMaterial>, mesh: Handle<Mesh>) -> impl Bundle {
+    (
+        Name::new("Barrel"),
+        Mesh3d(mesh),
+        MeshMaterial3d(material),
+        Transform::from_xyz(0., 0.01, 0.02).with_rotation(Quat::from_rotation_x(0.8)),
+    )
+}
+
+#[derive(Component)]
+pub struct MortarFlashLight;
+
+fn shot_flash_light(g_sett: &GraphicsSettings, rel_id: Entity, range: f32) -> impl Bundle {
+    (
+        Name::new("Shot Flash"),
+        PointLight {
+            intensity: 0.,
+            color: ORANGE.into(),
+            shadows_enabled: g_sett.is_shadows,
+            range,
+            ..default()
+        },
+        Transform::from_xyz(0., 0., 0.08),
+        Visibility::Hidden,
+        MortarFlashLight,
+        ShotLight,
+        RelEntity(rel_id),
+    )
+}
+
+const SHELL_ARC_HEIGHT: f32 = 0.15;
+const SHOT_FLASH_SECS: f32 = 0.15;
+
+#[derive(Component)]
+pub(in super::super) struct MortarShell {
+    tower_id: Entity,
+    start: Vec3,
+    target: Vec3,
+    flight: Timer,
+    splash: SplashDamage,
+}
+
+// Far shells fly longer
+fn flight_secs(distance: f32) -> f32 {
+    0.6 + distance * 1.5
+}
+
+type QueryMortarTower<'w, 's, 'a> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'a Tower,
+        &'a AimEnemy,
+        &'a Turret,
+        &'a SplashDamage,
+        &'a mut Reload,
+    ),
+    (With<MortarTower>, Without<Offline>),
+>;
+
+pub(in super::super) fn shoot_system(
+    mut cmds: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut mats: ResMut<Assets<StandardMaterial>>,
+    mut q_mortar: QueryMortarTower,
+    q_enemy: Query<(&Enemy, &Transform)>,
+    q_pbw: QueryWorld,
+    time: Res<Time>,
+) {
+    let Ok(world) = q_pbw.single() else {
+        return;
+    };
+    for (tower_id, tower, aim_enemy, turret, splash, mut reload) in q_mortar.iter_mut() {
+        reload.0.tick(time.delta());
+        if !reload.0.is_finished() || !turret.is_aligned {
+            continue;
+        }
+        let Some((enemy, enemy_trans)) = aim_enemy.0.and_then(|id| q_enemy.get(id).ok()) else {
+            continue;
+        };
+        // Aim at the position, where the enemy will be on impact
+        let start = tower.pos + Vec3::Z * 0.05;
+        let secs = flight_secs(start.distance(enemy_trans.translation));
+        let target = enemy_trans.translation + enemy.velocity() * secs;
+        reload.0.reset();
+        cmds.entity(world).with_children(|spawner| {
+            spawner.spawn(shell(
+                MortarShell {
+                    tower_id,
+                    start,
+                    target,
+                    flight: Timer::from_seconds(secs, TimerMode::Once),
+                    splash: *splash,
+                },
+                &mut meshes,
+                &mut mats,
+            ));
+        });
+    }
+}
+
+fn shell(
+    shell: MortarShell,
+    meshes: &mut Assets<Mesh>,
+    mats: &mut Assets<StandardMaterial>,
+) -> impl Bundle {
+    (
+        Name::new("Mortar Shell"),
+        Transform::from_translation(shell.start),
+        Mesh3d(meshes.add(Mesh::from(Sphere { radius: 0.008 }))),
+        MeshMaterial3d(mats.add(StandardMaterial {
+            base_color: DARK_SLATE_GRAY.into(),
+            perceptual_roughness: 0.4,
+            metallic: 0.8,
+            ..default()
+        })),
+        shell,
+    )
+}
+
+pub(in super::super) fn shell_flight_system(
+    mut cmds: Commands,
+    mut q_shell: Query<(Entity, &mut MortarShell, &mut Transform)>,
+    mut health_ev: MessageWriter<ChangeHealthEvent>,
+    q_enemy: Query<(Entity, &Enemy, &Transform), Without<MortarShell>>,
+    q_bonus: Query<&SynergyBonus>,
+    boost: Res<DamageBoost>,
+    time: Res<Time>,
+) {
+    for (shell_id, mut shell, mut trans) in q_shell.iter_mut() {
+        shell.flight.tick(time.delta());
+        let t = shell.flight.fraction();
+        let mut pos = shell.start.lerp(shell.target, t);
+        pos.z += SHELL_ARC_HEIGHT * 4. * t * (1. - t);
+        trans.translation = pos;
+        if shell.flight.is_finished() {
+            // Splash damage to every enemy near the impact
+            let impact = shell.target.truncate();
+            let bonus = q_bonus.get(shell.tower_id).ok();
+            q_enemy
+                .iter()
+                .filter(|(_, _, enemy_trans)| {
+                    enemy_trans.translation.truncate().distance(impact) <= shell.splash.radius
+                })
+                .for_each(|(enemy_id, enemy, _)| {
+                    let factor = bonus.map_or(1., |bonus| bonus.damage_factor(enemy));
+                    health_ev.write(ChangeHealthEvent::new(
+                        enemy_id,
+                        -shell.splash.damage * factor * boost.0,
+                        Some(shell.tower_id),
+                    ));
+                });
+            cmds.entity(shell_id).despawn();
+        }
+    }
+}
+
+pub(in super::super) fn shot_animation_system(
+    q_mortar: Query<(Entity, &Reload), With<MortarTower>>,
+    mut q_shot_flash: Query<(&mut Visibility, &mut PointLight, &RelEntity), With<MortarFlashLight>>,
+) {
+    for (tower_id, reload) in q_mortar.iter() {
+        let Some((mut visi, mut light, _)) = q_shot_flash
+            .iter_mut()
+            .find(|(_, _, rel_id)| rel_id.0 == tower_id)
+        else {
+            continue;
+        };
+        let since_shot = reload.secs_since_shot();
+        if since_shot < SHOT_FLASH_SECS {
+            *visi = Visibility::Inherited;
+            light.intensity = (1. - since_shot / SHOT_FLASH_SECS) * 64.;
+        } else if *visi != Visibility::Hidden {
+            *visi = Visibility::Hidden;
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use super::animations::RotateAlways;
-use super::{tower_material, TowerHead};
-use crate::game::tower::damage::{DamageAllTargetsInReach, DamageOverTime};
+use super::catalogue::TowerStats;
+use super::{tower_material, TowerHead, TowerType};
+use crate::game::power::Offline;
+use crate::game::tower::damage::{ChainCount, DamageAllTargetsInReach, DamageOverTime};
 use crate::game::tower::target::EnemiesWithinReach;
 use crate::game::tower::ShotLight;
 use crate::prelude::*;
@@ -17,8 +19,9 @@ pub fn spawn(
     assets: &PinballDefenseGltfAssets,
     g_sett: &GraphicsSettings,
     pos: Vec3,
+    stats: &TowerStats,
 ) {
-    let sight_radius = 0.15;
+    let sight_radius = stats.sight_radius;
     let tower_mat = mats.add(tower_material());
     super::spawn(
         pb_world,
@@ -26,12 +29,14 @@ pub fn spawn(
         assets,
         g_sett,
         pos,
-        sight_radius,
+        TowerType::Tesla,
+        stats,
         (
             Name::new("Tesla Tower"),
             TeslaTower,
             DamageAllTargetsInReach,
-            DamageOverTime(15.),
+            ChainCount(stats.chain_count),
+            DamageOverTime(stats.damage),
         ),
         |tower| {
             tower.spawn(top(tower_mat.clone(), assets));
@@ -77,12 +82,12 @@ fn shot_flash_light(g_sett: &GraphicsSettings, rel_id: Entity, range: f32) -> im
 
 pub(in super::super) fn shot_animation_system(
     time: Res<Time>,
-    q_tesla: Query<(Entity, &EnemiesWithinReach), With<TeslaTower>>,
+    q_tesla: Query<(Entity, &EnemiesWithinReach, Has<Offline>), With<TeslaTower>>,
     mut q_shot_flash: Query<(&mut Visibility, &mut PointLight, &RelEntity), With<ShotFlashLight>>,
 ) {
-    for (tower_id, ewr) in q_tesla.iter() {
+    for (tower_id, ewr, is_offline) in q_tesla.iter() {
         let mut flash = get_flash(&mut q_shot_flash, tower_id);
-        match ewr.0.is_empty() {
+        match ewr.0.is_empty() || is_offline {
             false => {
                 let sin = (time.elapsed_secs() * 32.).sin();
                 *flash.0 = Visibility::Inherited;
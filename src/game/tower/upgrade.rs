@@ -0,0 +1,170 @@
+use super::animations::Turret;
+use super::catalogue::TowerCatalogue;
+use super::damage::{ChainCount, SplashDamage};
+use super::projectile::Reload;
+use super::speed::SlowDownFactor;
+use super::{Tower, TowerType, TowerUpgrade};
+use crate::prelude::*;
+use bevy::platform::collections::HashMap;
+
+struct UpgradeNode {
+    upgrade: TowerUpgrade,
+    // Targeting stays at tier 0, because it is no level up
+    max_tier: u8,
+    // Other upgrades with the tier, they need to have
+    requires: &'static [(TowerUpgrade, u8)],
+}
+
+const fn node(
+    upgrade: TowerUpgrade,
+    max_tier: u8,
+    requires: &'static [(TowerUpgrade, u8)],
+) -> UpgradeNode {
+    UpgradeNode {
+        upgrade,
+        max_tier,
+        requires,
+    }
+}
+
+const GUN_TREE: [UpgradeNode; 5] = [
+    node(TowerUpgrade::Targeting, 1, &[]),
+    node(TowerUpgrade::Damage, 5, &[]),
+    node(TowerUpgrade::Range, 3, &[]),
+    node(TowerUpgrade::RotationSpeed, 3, &[]),
+    node(
+        TowerUpgrade::FireRate,
+        4,
+        &[(TowerUpgrade::RotationSpeed, 1)],
+    ),
+];
+
+const TESLA_TREE: [UpgradeNode; 3] = [
+    node(TowerUpgrade::Damage, 5, &[]),
+    node(TowerUpgrade::Range, 3, &[]),
+    node(TowerUpgrade::ChainCount, 4, &[(TowerUpgrade::Damage, 1)]),
+];
+
+const MICROWAVE_TREE: [UpgradeNode; 4] = [
+    node(TowerUpgrade::Targeting, 1, &[]),
+    node(TowerUpgrade::Range, 3, &[]),
+    node(TowerUpgrade::RotationSpeed, 2, &[]),
+    node(TowerUpgrade::SlowStrength, 5, &[]),
+];
+
+const MORTAR_TREE: [UpgradeNode; 6] = [
+    node(TowerUpgrade::Targeting, 1, &[]),
+    node(TowerUpgrade::Damage, 5, &[]),
+    node(TowerUpgrade::Range, 3, &[]),
+    node(TowerUpgrade::RotationSpeed, 2, &[]),
+    node(TowerUpgrade::DamageRadius, 3, &[(TowerUpgrade::Damage, 1)]),
+    node(
+        TowerUpgrade::FireRate,
+        3,
+        &[(TowerUpgrade::DamageRadius, 1)],
+    ),
+];
+
+fn upgrade_tree(tower_type: TowerType) -> &'static [UpgradeNode] {
+    match tower_type {
+        TowerType::Gun => &GUN_TREE,
+        TowerType::Tesla => &TESLA_TREE,
+        TowerType::Microwave => &MICROWAVE_TREE,
+        TowerType::Mortar => &MORTAR_TREE,
+    }
+}
+
+// How often each upgrade was taken by a tower
+#[derive(Component, Default)]
+pub struct UpgradeTiers(HashMap<TowerUpgrade, u8>);
+
+impl UpgradeTiers {
+    fn tier(&self, upgrade: TowerUpgrade) -> u8 {
+        self.0.get(&upgrade).copied().unwrap_or(0)
+    }
+
+    pub(super) fn add(&mut self, upgrade: TowerUpgrade) {
+        *self.0.entry(upgrade).or_default() += 1;
+    }
+
+    // Upgrades, the tower can take next, out of the unlocked ones
+    pub fn available(&self, tower_type: TowerType, unlocked: &[TowerUpgrade]) -> Vec<TowerUpgrade> {
+        upgrade_tree(tower_type)
+            .iter()
+            .filter(|node| unlocked.contains(&node.upgrade))
+            .filter(|node| self.tier(node.upgrade) < node.max_tier)
+            .filter(|node| {
+                node.requires
+                    .iter()
+                    .all(|(upgrade, tier)| self.tier(*upgrade) >= *tier)
+            })
+            .map(|node| node.upgrade)
+            // Every tower can be recycled
+            .chain(std::iter::once(TowerUpgrade::Recycle))
+            .collect()
+    }
+}
+
+// Upgrades, which only change a stat of the tower
+#[derive(Message)]
+pub(super) struct StatUpgradeEvent(pub Entity, pub TowerUpgrade);
+
+type QueryTowerStats<'w, 's, 'a> = Query<
+    'w,
+    's,
+    (
+        &'a TowerType,
+        Option<&'a mut Turret>,
+        Option<&'a mut SplashDamage>,
+        Option<&'a mut Reload>,
+        Option<&'a mut SlowDownFactor>,
+        Option<&'a mut ChainCount>,
+    ),
+    With<Tower>,
+>;
+
+pub(super) fn on_stat_upgrade_system(
+    mut evr: MessageReader<StatUpgradeEvent>,
+    mut q_tower: QueryTowerStats,
+    data: Res<PinballDefenseAssets>,
+    catalogues: Res<Assets<TowerCatalogue>>,
+) {
+    let catalogue = TowerCatalogue::get(&data, &catalogues);
+    for StatUpgradeEvent(tower_id, upgrade) in evr.read() {
+        let Ok((tower_type, turret, splash, reload, slow_down, chain_count)) =
+            q_tower.get_mut(*tower_id)
+        else {
+            continue;
+        };
+        let scaling = &catalogue.stats(*tower_type).upgrade;
+        match upgrade {
+            TowerUpgrade::RotationSpeed => {
+                if let Some(mut turret) = turret {
+                    turret.rotation_speed *= scaling.rotation_speed_factor;
+                }
+            }
+            TowerUpgrade::DamageRadius => {
+                if let Some(mut splash) = splash {
+                    splash.radius += scaling.damage_radius_add;
+                }
+            }
+            TowerUpgrade::FireRate => {
+                if let Some(mut reload) = reload {
+                    let duration = reload.0.duration().mul_f32(scaling.reload_factor);
+                    reload.0.set_duration(duration);
+                }
+            }
+            TowerUpgrade::SlowStrength => {
+                if let Some(mut slow_down) = slow_down {
+                    slow_down.0 *= scaling.slow_down_factor;
+                }
+            }
+            TowerUpgrade::ChainCount => {
+                if let Some(mut chain_count) = chain_count {
+                    chain_count.0 += scaling.chain_count_add;
+                }
+            }
+            _ => (),
+        }
+    }
+}
@@ -20,6 +20,7 @@ pub fn spawn(mut cmd: Commands, ctl: Res<KeyboardControls>, ass: Res<PinballDefe
     spawn_key(cmd, ctl.menu, &ass, TopLeft, "Menu");
     spawn_key(cmd, ctl.pause, &ass, TopRight(0), "Pause");
     spawn_key(cmd, ctl.toggle_key_ui, &ass, TopRight(1), "Toggle Keys UI");
+    spawn_key(cmd, ctl.nudge, &ass, TopRight(2), "Nudge");
 }
 
 pub fn despawn(mut cmds: Commands, q_ui: Query<Entity, With<ControlsUi>>) {
@@ -1,14 +1,21 @@
-use super::enemy::SpawnEnemyEvent;
+use super::enemy::{EnemyKind, SpawnEnemyEvent};
 use super::GameState;
 use super::IngameTime;
 use crate::prelude::*;
+use serde::Deserialize;
+use std::collections::VecDeque;
 
 pub struct WavePlugin;
 
 impl Plugin for WavePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(GameState::Init), init_resources)
-            .add_systems(Update, (wave_system).run_if(in_state(GameState::Ingame)));
+        app.init_asset::<WaveCampaign>()
+            .register_asset_loader(RonAssetLoader::<WaveCampaign>::new(&["waves.ron"]))
+            .add_systems(OnEnter(GameState::Init), init_resources)
+            .add_systems(
+                Update,
+                (wave_system, on_campaign_modified_system).run_if(in_state(GameState::Ingame)),
+            );
     }
 }
 
@@ -16,64 +23,188 @@ fn init_resources(mut cmds: Commands) {
     cmds.insert_resource(Wave::default());
 }
 
-#[derive(Resource)]
-struct Wave {
-    number: usize,
-    enemies_count: usize,
-    next_enemy_spawn_time: f32,
-    time_between_enemies: f32,
+// Describes the whole wave campaign of a world, loaded from assets/data/*.waves.ron
+#[derive(Asset, TypePath, Deserialize)]
+pub struct WaveCampaign {
+    waves: Vec<WaveDef>,
+    // Fallback for every wave after the last defined one
+    endless: EndlessWaves,
 }
 
-impl Default for Wave {
-    fn default() -> Self {
-        Self {
-            number: 0,
-            enemies_count: 0,
-            next_enemy_spawn_time: 0.,
-            time_between_enemies: 1.,
+impl ValidateAsset for WaveCampaign {}
+
+impl WaveCampaign {
+    fn wave(&self, number: usize) -> WaveDef {
+        match self.waves.get(number - 1) {
+            Some(wave) => wave.clone(),
+            None => self.endless.wave(number),
         }
     }
 }
 
+#[derive(Deserialize, Clone)]
+struct WaveDef {
+    // Seconds between the end of the previous wave and the start of this wave
+    delay: f32,
+    groups: Vec<EnemyGroup>,
+}
+
+#[derive(Deserialize, Clone)]
+struct EnemyGroup {
+    #[serde(default)]
+    enemy: EnemyKind,
+    // Index of the road portal, where the group enters the road, random if none
+    #[serde(default)]
+    portal: Option<usize>,
+    count: usize,
+    // Seconds between two enemies of this group
+    interval: f32,
+    // Seconds between the start of the wave and the first enemy of this group
+    #[serde(default)]
+    delay: f32,
+}
+
+#[derive(Deserialize)]
+struct EndlessWaves {
+    delay: f32,
+    enemies_per_wave: f32,
+    #[serde(default)]
+    tanks_per_wave: f32,
+    #[serde(default)]
+    speeders_per_wave: f32,
+    interval: f32,
+    interval_decay: f32,
+}
+
+impl EndlessWaves {
+    fn wave(&self, number: usize) -> WaveDef {
+        let interval = self.interval * self.interval_decay.powi(number as i32);
+        let group = |enemy: EnemyKind, per_wave: f32, delay: f32| EnemyGroup {
+            enemy,
+            portal: None,
+            count: (number as f32 * per_wave) as usize,
+            interval,
+            delay,
+        };
+        WaveDef {
+            delay: self.delay,
+            groups: vec![
+                group(EnemyKind::Normal, self.enemies_per_wave, 0.),
+                group(EnemyKind::Speeder, self.speeders_per_wave, interval / 2.),
+                group(EnemyKind::Tank, self.tanks_per_wave, interval * 2.),
+            ],
+        }
+    }
+}
+
+struct PlannedEnemy {
+    time: f32,
+    kind: EnemyKind,
+    portal: Option<usize>,
+}
+
+#[derive(Resource, Default)]
+struct Wave {
+    number: usize,
+    start: f32,
+    // Enemies of the running wave, which are already on the road
+    spawned: usize,
+    enemies: VecDeque<PlannedEnemy>,
+}
+
 impl Wave {
     fn is_time_to_spawn_enemy(&self, now: f32) -> bool {
-        now >= self.next_enemy_spawn_time
+        self.enemies.front().is_some_and(|enemy| now >= enemy.time)
     }
 
     fn is_wave_end(&self) -> bool {
-        self.enemies_count == 0
+        self.enemies.is_empty()
     }
 
-    fn next_enemy(&mut self, now: f32) -> SpawnEnemyEvent {
-        self.enemies_count -= 1;
-        self.next_enemy_spawn_time = now + self.time_between_enemies;
-        SpawnEnemyEvent
+    fn next_enemy(&mut self) -> Option<SpawnEnemyEvent> {
+        let enemy = self.enemies.pop_front()?;
+        self.spawned += 1;
+        Some(SpawnEnemyEvent::new(enemy.kind, enemy.portal))
     }
 
-    fn prepare_next_wave(&mut self, now: f32) {
+    fn prepare_next_wave(&mut self, now: f32, campaign: &WaveCampaign) {
         self.number += 1;
-        self.next_enemy_spawn_time = (now + TIME_BETWEEN_WAVES).round();
-        self.enemies_count = (self.number as f32 * 1.5) as usize;
-        self.time_between_enemies *= 0.999;
-        log!("🏄‍♂️ Wave end. Wait until {}", self.next_enemy_spawn_time);
+        let wave = campaign.wave(self.number);
+        self.start = (now + wave.delay).round();
+        self.spawned = 0;
+        self.plan_enemies(&wave);
+        log!("🏄‍♂️ Wave {} starts at {}", self.number, self.start);
     }
-}
 
-const TIME_BETWEEN_WAVES: f32 = 8.;
+    // Uses the changed campaign for the enemies of the running wave, which aren't spawned yet
+    fn replan_wave(&mut self, campaign: &WaveCampaign) {
+        if self.number == 0 {
+            return;
+        }
+        self.plan_enemies(&campaign.wave(self.number));
+        log!(
+            "🌊 Wave {} has {} enemies left after reload",
+            self.number,
+            self.enemies.len()
+        );
+    }
+
+    fn plan_enemies(&mut self, wave: &WaveDef) {
+        let start = self.start;
+        let mut enemies: Vec<PlannedEnemy> = wave
+            .groups
+            .iter()
+            .flat_map(|group| {
+                (0..group.count).map(move |i| PlannedEnemy {
+                    time: start + group.delay + i as f32 * group.interval,
+                    kind: group.enemy,
+                    portal: group.portal,
+                })
+            })
+            .collect();
+        enemies.sort_by(|a, b| a.time.total_cmp(&b.time));
+        self.enemies = enemies.into_iter().skip(self.spawned).collect();
+    }
+}
 
 fn wave_system(
     mut wave: ResMut<Wave>,
     mut spawn_enemy_ev: MessageWriter<SpawnEnemyEvent>,
     ig_timer: Res<IngameTime>,
+    assets: Res<PinballDefenseAssets>,
+    campaigns: Res<Assets<WaveCampaign>>,
 ) {
     let now = **ig_timer;
     let wave = wave.as_mut();
-    if wave.is_time_to_spawn_enemy(now) {
-        match wave.is_wave_end() {
-            true => wave.prepare_next_wave(now),
-            false => {
-                spawn_enemy_ev.write(wave.next_enemy(now));
-            }
+    if wave.is_wave_end() {
+        if let Some(campaign) = campaigns.get(&assets.waves) {
+            wave.prepare_next_wave(now, campaign);
+        }
+    }
+    while wave.is_time_to_spawn_enemy(now) {
+        if let Some(ev) = wave.next_enemy() {
+            spawn_enemy_ev.write(ev);
+        }
+    }
+}
+
+// Changes in the campaign file are used for the rest of the running wave
+fn on_campaign_modified_system(
+    mut evr: MessageReader<AssetEvent<WaveCampaign>>,
+    mut wave: ResMut<Wave>,
+    assets: Res<PinballDefenseAssets>,
+    campaigns: Res<Assets<WaveCampaign>>,
+) {
+    for ev in evr.read() {
+        let AssetEvent::Modified { id } = ev else {
+            continue;
+        };
+        if *id != assets.waves.id() {
+            continue;
+        }
+        if let Some(campaign) = campaigns.get(*id) {
+            log!("🌊 Wave campaign reloaded");
+            wave.replan_wave(campaign);
         }
     }
 }
@@ -1,18 +1,23 @@
 use super::events::collision::GameLayer;
 
 use super::analog_counter;
-use super::level::{LevelCounterId, PointCounterId};
+use super::ball_inventory::BallCounterId;
+use super::ball_save::BallSaveLamp;
+use super::base::spawn_base;
+use super::level::{ComboCounterId, LevelCounterId, PointCounterId};
 use super::light::spawn_lamp;
-use super::light::LevelUpLamp;
+use super::light::{ComboLamp, LevelUpLamp};
 use super::pinball_menu::pinball_menu_glass;
-use super::player_life::spawn_life_bar;
-use super::road::spawn_road;
+use super::road::{spawn_road, RoadGraph, RoadLayout};
+use super::skill_shot;
+use super::table::{spawn_table_elements, TableLayout};
+use super::tilt::TiltLamp;
 use super::tower::foundation;
 use crate::assets::PinballDefenseGltfAssets;
 use crate::generated::world_1::*;
 use crate::prelude::*;
 use crate::settings::GraphicsSettings;
-use bevy::color::palettes::css::{RED, TOMATO};
+use bevy::color::palettes::css::{DEEP_SKY_BLUE, ORANGE, RED, TOMATO};
 
 pub type QueryWorld<'w, 's> = Query<'w, 's, Entity, With<PinballWorld>>;
 
@@ -27,11 +32,18 @@ pub fn spawn_pinball_world(
     mut meshes: ResMut<Assets<Mesh>>,
     mut mats: ResMut<Assets<StandardMaterial>>,
     assets: Res<PinballDefenseGltfAssets>,
+    data: Res<PinballDefenseAssets>,
+    layouts: Res<Assets<TableLayout>>,
+    road_layouts: Res<Assets<RoadLayout>>,
     g_sett: Res<GraphicsSettings>,
 ) {
     let assets = assets.as_ref();
+    let layout = TableLayout::get(&data, &layouts);
+    let road = RoadGraph::new(RoadLayout::get(&data, &road_layouts));
     let mut pc_id = None;
     let mut lc_id = None;
+    let mut cc_id = None;
+    let mut bc_id = None;
     //let mut img_handle: Option<Handle<Image>> = None;
     cmds.spawn((
         PinballWorld,
@@ -65,25 +77,22 @@ pub fn spawn_pinball_world(
 
         // Ball starter
         let bs_pos = Vec3::new(1.284, 0.657, -0.018);
-        super::ball_starter::spawn(p, bs_pos, assets);
+        super::ball_starter::spawn(p, &mut mats, &mut meshes, bs_pos, assets);
 
         // Flipper left
         let fl_pos = Transform::from_xyz(0.83, -0.32, -0.043);
-        super::flipper::spawn_left(fl_pos, p, assets);
+        super::flipper::spawn_left(fl_pos, p, assets, layout.left_flipper);
 
         // Flipper right
         let fr_pos = Transform::from_xyz(0.83, 0.246, -0.043);
-        super::flipper::spawn_right(fr_pos, p, assets);
+        super::flipper::spawn_right(fr_pos, p, assets, layout.right_flipper);
 
         spawn_build_marks(p, assets);
-        spawn_road(p, &mut mats, &mut meshes, assets);
+        spawn_road(p, &mut mats, &mut meshes, assets, &road);
+        spawn_table_elements(p, &mut mats, &mut meshes, assets, &g_sett, layout);
+        skill_shot::spawn_targets(p, &mut mats, &mut meshes, assets, &g_sett);
 
-        let life_bar_trans = Transform {
-            translation: Vec3::new(1.15, -0.035, -0.05),
-            scale: Vec3::new(4., 4., 4.),
-            ..default()
-        };
-        spawn_life_bar(p, assets, &mut mats, life_bar_trans);
+        spawn_base(p, &mut mats, &mut meshes, assets, &g_sett);
         p.spawn(pinball_menu_glass(assets, &mut mats));
         //img_handle = Some(spawn_point_display(p, &mut mats, &mut images, assets));
         pc_id = Some(analog_counter::spawn_10_digit(
@@ -108,6 +117,48 @@ pub fn spawn_pinball_world(
             TOMATO.into(),
             LevelUpLamp,
         );
+        cc_id = Some(analog_counter::spawn_2_digit(
+            p,
+            assets,
+            Transform::from_xyz(0.98, -0.3, 0.01),
+            None,
+        ));
+        let combo_lamp_pos = Vec3::new(1., -0.2, 0.06);
+        spawn_lamp(
+            p,
+            &mut mats,
+            assets,
+            &g_sett,
+            combo_lamp_pos,
+            ORANGE.into(),
+            ComboLamp,
+        );
+        let tilt_lamp_pos = Vec3::new(1., 0.2, 0.06);
+        spawn_lamp(
+            p,
+            &mut mats,
+            assets,
+            &g_sett,
+            tilt_lamp_pos,
+            RED.into(),
+            TiltLamp,
+        );
+        bc_id = Some(analog_counter::spawn_2_digit(
+            p,
+            assets,
+            Transform::from_xyz(0.98, 0., 0.01),
+            None,
+        ));
+        let ball_save_lamp_pos = Vec3::new(1.1, 0.55, 0.06);
+        spawn_lamp(
+            p,
+            &mut mats,
+            assets,
+            &g_sett,
+            ball_save_lamp_pos,
+            DEEP_SKY_BLUE.into(),
+            BallSaveLamp,
+        );
     });
 
     cmds.insert_resource(PointCounterId(
@@ -116,6 +167,14 @@ pub fn spawn_pinball_world(
     cmds.insert_resource(LevelCounterId(
         lc_id.expect("Level Counter Id can not be None here!"),
     ));
+    cmds.insert_resource(ComboCounterId(
+        cc_id.expect("Combo Counter Id can not be None here!"),
+    ));
+    cmds.insert_resource(BallCounterId(
+        bc_id.expect("Ball Counter Id can not be None here!"),
+    ));
+    // Using insert_resource to reset previous resources of same type
+    cmds.insert_resource(road);
     //if let Some(img) = img_handle {
     //spawn_point_display_ui_and_cam(&mut cmds, assets, img);
     //}
@@ -37,6 +37,8 @@ fn main() {
     app.add_plugins((
         DefaultPlugins.set(AssetPlugin {
             unapproved_path_mode: bevy::asset::UnapprovedPathMode::Allow,
+            // Hot reload game data like wave campaigns while developing
+            watch_for_changes_override: Some(cfg!(feature = "dev")),
             ..default()
         }),
         bevy_framepace::FramepacePlugin,